@@ -0,0 +1,68 @@
+// Compares notify with a single observer, which takes the fast path, against a single filtered
+// observer and several observers, which go through the fan-out loop.
+//
+// criterion isn't a dependency, so this is a plain harness. Run with `cargo bench --bench notify`.
+//
+use
+{
+	pharos  :: { *                                    } ,
+	futures :: { executor::block_on, SinkExt, StreamExt } ,
+	std     :: { time::{ Duration, Instant }            } ,
+};
+
+
+const EVENTS: usize = 10_000;
+const ROUNDS: u32   = 50;
+
+
+
+fn main()
+{
+	let plain    = || ObserveConfig::default().channel( Channel::Unbounded );
+	let filtered = || plain().filter( |_: &usize| true );
+
+	bench( "one observer"            , 1, plain    );
+	bench( "one observer with filter", 1, filtered );
+	bench( "four observers"          , 4, plain    );
+}
+
+
+
+// Sends EVENTS events to `num` observers and drains them, ROUNDS times, and prints the time per event.
+//
+fn bench( name: &str, num: usize, opts: impl Fn() -> ObserveConfig<usize> )
+{
+	let mut total = Duration::default();
+
+	block_on( async
+	{
+		for _ in 0..ROUNDS
+		{
+			let mut ph     = Pharos::<usize>::default();
+			let mut events = Vec::with_capacity( num );
+
+			for _ in 0..num
+			{
+				events.push( ph.observe( opts() ).await.expect( "observe" ) );
+			}
+
+			let start = Instant::now();
+
+			for i in 0..EVENTS
+			{
+				ph.send( i ).await.expect( "send" );
+			}
+
+			total += start.elapsed();
+
+			ph.close().await.expect( "close" );
+
+			for evts in &mut events
+			{
+				assert_eq!( EVENTS, evts.count().await );
+			}
+		}
+	});
+
+	println!( "{:<26} {:>8.1} ns/event", name, total.as_nanos() as f64 / f64::from( ROUNDS ) / EVENTS as f64 );
+}
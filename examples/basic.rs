@@ -67,7 +67,7 @@ async fn main()
 	// - channel type (bounded/unbounded)
 	// - a predicate to filter events
 	//
-	let mut events = isis.observe( Channel::Bounded( 3 ).into() ).await.expect( "observe" );
+	let mut events = isis.observe_bounded( 3, None ).await.expect( "observe" );
 
 	// trigger an event
 	//
@@ -35,17 +35,16 @@ async fn main()
 	//
 	let filter = Filter::Pointer( |e| e == &NetworkEvent::Closed );
 
-	// By creating the config object through into, other options will be defaults, notably here
-	// this will use unbounded channels.
+	// This uses an unbounded channel.
 	//
-	let observer = conn.observe( filter.into() ).await.expect( "observe" );
+	let observer = conn.observe_unbounded( filter ).await.expect( "observe" );
 
 	// Combine both options.
 	//
 	let filter = Filter::Pointer( |e| e != &NetworkEvent::Closed );
-	let opts   = ObserveConfig::from( filter ).channel( Channel::Bounded(5) );
 
 	// Get everything but close events over a bounded channel with queue size 5.
+	// Use ObserveConfig if you need any of the other options.
 	//
-	let bounded_observer = conn.observe( opts );
+	let bounded_observer = conn.observe_bounded( 5, filter ).await.expect( "observe" );
 }
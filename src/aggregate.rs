@@ -0,0 +1,236 @@
+use crate :: { import::*, Events, Timer, events::Adapter };
+
+
+/// Statistics over the events observed during one window of [Events::aggregate].
+///
+/// For an empty window, all fields are zero.
+//
+#[ derive( Debug, Copy, Clone, PartialEq, Default ) ]
+//
+pub struct Aggregate
+{
+	/// The number of events in the window.
+	//
+	pub count: usize,
+
+	/// The smallest value in the window.
+	//
+	pub min: f64,
+
+	/// The biggest value in the window.
+	//
+	pub max: f64,
+
+	/// The sum of all values in the window.
+	//
+	pub sum: f64,
+
+	/// The mean of all values in the window.
+	//
+	pub mean: f64,
+}
+
+
+impl Aggregate
+{
+	fn add( &mut self, value: f64 )
+	{
+		if self.count == 0
+		{
+			self.min = value;
+			self.max = value;
+		}
+
+		else
+		{
+			self.min = self.min.min( value );
+			self.max = self.max.max( value );
+		}
+
+		self.count += 1;
+		self.sum   += value;
+		self.mean   = self.sum / self.count as f64;
+	}
+}
+
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Emit statistics over the numeric value of events per time window. See [aggregate_by](Events::aggregate_by),
+	/// this uses the `Into<f64>` impl of the event and windows without events emit nothing.
+	//
+	pub fn aggregate( self, window: Duration, timer: impl Timer ) -> Events<Aggregate>
+
+		where Event: Into<f64>
+	{
+		self.aggregate_by( window, timer, Into::into, false )
+	}
+
+
+	/// Emit an [Aggregate] with the count, minimum, maximum, sum and mean of the values produced by `project`
+	/// for all events received during each `window`.
+	///
+	/// The first window starts when you call this method, the next one as soon as a window closes, also for timers
+	/// that only start when they are polled. A window closes when it's time is up, even if more events are ready,
+	/// so under load windows don't grow. Every window reads the events at least once, so a timer that is ready right
+	/// away doesn't keep them from being read. Events count for the window that is open when they are read. When
+	/// `emit_empty` is true, windows without any events produce an aggregate with a count of zero, otherwise they
+	/// produce nothing.
+	///
+	/// When the observable ends the stream (or you call [close](Events::close) and the pending events have been
+	/// consumed), the statistics of the last, incomplete window are emitted if it contains any events, after
+	/// which the stream ends.
+	//
+	pub fn aggregate_by<P>( self, window: Duration, timer: impl Timer, project: P, emit_empty: bool ) -> Events<Aggregate>
+
+		where P: FnMut( Event ) -> f64 + Send + 'static
+	{
+		let sleep = Box::pin( timer.sleep( window ) );
+
+		Events::from_adapter( self, |events|
+		{
+			let mut aggregator = Aggregator
+			{
+				acc    : Aggregate::default() ,
+				done   : false                ,
+				elapsed: false                ,
+				read   : false                ,
+				events                        ,
+				timer                         ,
+				window                        ,
+				sleep                         ,
+				project                       ,
+				emit_empty                    ,
+			};
+
+			aggregator.poll_sleep( &mut Context::from_waker( futures::task::noop_waker_ref() ) );
+			aggregator
+		})
+	}
+}
+
+
+
+struct Aggregator<Event, T, P> where Event: Clone + 'static + Send, T: Timer
+{
+	events    : Events<Event>        ,
+	timer     : T                    ,
+	window    : Duration             ,
+	sleep     : Pin<Box< T::Sleep >> ,
+
+	// Whether sleep has resolved. It must not be polled again.
+	//
+	elapsed   : bool                 ,
+
+	// Whether the events have been polled since the window opened. A window only closes after that, so
+	// a timer that is ready right away can't keep us from reading events.
+	//
+	read      : bool                 ,
+	acc       : Aggregate            ,
+	project   : P                    ,
+	emit_empty: bool                 ,
+	done      : bool                 ,
+}
+
+
+
+impl<Event, T, P> Adapter<Aggregate> for Aggregator<Event, T, P>
+
+	where Event: Clone + 'static + Send       ,
+	      T    : Timer                        ,
+	      P    : FnMut( Event ) -> f64 + Send ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Aggregate> >
+	{
+		if self.done
+		{
+			return Poll::Ready( None );
+		}
+
+		loop
+		{
+			// Check the window before every event, so it closes in time when events keep coming.
+			//
+			if self.read && self.poll_sleep( cx )
+			{
+				self.read = false;
+				self.next_window( cx );
+
+				let acc = std::mem::take( &mut self.acc );
+
+				if acc.count > 0 || self.emit_empty
+				{
+					return Poll::Ready( Some( acc ) );
+				}
+			}
+
+			self.read = true;
+
+
+			let evt = match Pin::new( &mut self.events ).poll_next( cx )
+			{
+				Poll::Ready( evt )                     => evt                  ,
+
+				// Make sure the timer wakes us, the window might have closed in the mean time.
+				//
+				Poll::Pending if self.poll_sleep( cx ) => continue            ,
+				Poll::Pending                          => return Poll::Pending ,
+			};
+
+
+			match evt
+			{
+				Some( evt ) =>
+				{
+					let value = (self.project)( evt );
+
+					self.acc.add( value );
+				}
+
+				None =>
+				{
+					self.done = true;
+
+					let acc = std::mem::take( &mut self.acc );
+
+					return Poll::Ready( if acc.count > 0 { Some( acc ) } else { None } );
+				}
+			}
+		}
+	}
+
+
+	fn close( &mut self )
+	{
+		self.events.close();
+	}
+}
+
+
+
+impl<Event, T, P> Aggregator<Event, T, P> where Event: Clone + 'static + Send, T: Timer
+{
+	// Whether the current window is over.
+	//
+	fn poll_sleep( &mut self, cx: &mut Context<'_> ) -> bool
+	{
+		if !self.elapsed
+		{
+			self.elapsed = self.sleep.as_mut().poll( cx ).is_ready();
+		}
+
+		self.elapsed
+	}
+
+
+	// Open the next window. The timer is polled right away, so it starts counting.
+	//
+	fn next_window( &mut self, cx: &mut Context<'_> )
+	{
+		self.sleep   = Box::pin( self.timer.sleep( self.window ) );
+		self.elapsed = false;
+
+		self.poll_sleep( cx );
+	}
+}
@@ -0,0 +1,235 @@
+use crate :: { import::*, Events, Timer, events::Adapter };
+use std   :: { collections::HashMap, hash::Hash };
+
+
+/// An item of [Events::backoff_dedup].
+//
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+//
+pub enum Backoff<Event>
+{
+	/// An event with a key that was not being suppressed. It's delivered right away.
+	//
+	First( Event ),
+
+	/// `count` events with the same key were suppressed during a backoff window. `event` is the last of them.
+	//
+	Repeated
+	{
+		/// The last event that was suppressed.
+		//
+		event: Event,
+
+		/// How many events were suppressed.
+		//
+		count: usize,
+	},
+}
+
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Suppress repeated events with escalating backoff, eg. for a noisy stream of errors. Events are grouped
+	/// by the key returned by `key`.
+	///
+	/// The first event for a key is delivered right away as [Backoff::First] and opens a backoff window of
+	/// `initial`. Events with that key arriving during the window are suppressed. When the window closes, they are
+	/// summarized in one [Backoff::Repeated] and a new window opens that is twice as long, up to `max_backoff`. When
+	/// a whole window passes without events for a key, it's forgotten, so the next event for it is delivered right
+	/// away again.
+	///
+	/// Every key that is being suppressed keeps a timer from `timer` and it's last event. All timers are polled when
+	/// the stream is polled, so this is meant for a moderate number of keys. When the observable ends the stream,
+	/// the events that are still suppressed are summarized before the stream ends.
+	///
+	/// ```
+	/// use pharos::*;
+	/// use std::time::Duration;
+	///
+	/// #[ derive( Clone ) ] struct Failure { code: u16, msg: String }
+	///
+	/// # fn task( events: Events<Failure> ) {
+	/// let quiet = events.backoff_dedup
+	/// (
+	///    |f| f.code               ,
+	///    Duration::from_secs(  1 ),
+	///    Duration::from_secs( 60 ),
+	///    async_std::task::sleep   ,
+	/// );
+	/// # }
+	/// ```
+	//
+	pub fn backoff_dedup<K, F>( self, key: F, initial: Duration, max_backoff: Duration, timer: impl Timer ) -> Events<Backoff<Event>>
+
+		where K: Hash + Eq + Clone + Send + 'static    ,
+		      F: FnMut( &Event ) -> K + Send + 'static ,
+	{
+		Events::from_adapter( self, |events| Dedup
+		{
+			keys : HashMap::new()  ,
+			ready: VecDeque::new() ,
+			done : false           ,
+			events                 ,
+			key                    ,
+			initial                ,
+			max_backoff            ,
+			timer                  ,
+		})
+	}
+}
+
+
+
+struct Dedup<Event, K, F, T> where Event: Clone + 'static + Send, T: Timer
+{
+	events     : Events<Event>                  ,
+	key        : F                              ,
+	initial    : Duration                       ,
+	max_backoff: Duration                       ,
+	timer      : T                              ,
+	keys       : HashMap< K, Window<Event, T> > ,
+
+	// Items that can be delivered.
+	//
+	ready      : VecDeque< Backoff<Event> >     ,
+	done       : bool                           ,
+}
+
+
+
+// The backoff of a key that is being suppressed.
+//
+struct Window<Event, T> where T: Timer
+{
+	length: Duration             ,
+	sleep : Pin<Box< T::Sleep >> ,
+	last  : Option<Event>        ,
+	count : usize                ,
+}
+
+
+
+impl<Event, K, F, T> Adapter< Backoff<Event> > for Dedup<Event, K, F, T>
+
+	where Event: Clone + 'static + Send                ,
+	      K    : Hash + Eq + Clone + Send + 'static    ,
+	      F    : FnMut( &Event ) -> K + Send + 'static ,
+	      T    : Timer                                 ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Backoff<Event>> >
+	{
+		// Close the windows that have passed first, the events waiting in the channel might have
+		// arrived after that. Every timer we keep has been polled, so we will be woken up.
+		//
+		if !self.done
+		{
+			self.expire( cx );
+		}
+
+
+		while !self.done
+		{
+			match Pin::new( &mut self.events ).poll_next( cx )
+			{
+				Poll::Ready( Some(evt) ) =>
+				{
+					let key = (self.key)( &evt );
+
+					match self.keys.get_mut( &key )
+					{
+						Some( window ) =>
+						{
+							window.last   = Some( evt );
+							window.count += 1;
+						}
+
+						None =>
+						{
+							let sleep = Box::pin( self.timer.sleep( self.initial ) );
+
+							self.keys .insert( key, Window{ length: self.initial, sleep, last: None, count: 0 } );
+							self.ready.push_back( Backoff::First( evt ) );
+						}
+					}
+				}
+
+				Poll::Ready( None ) => self.done = true,
+				Poll::Pending       => break,
+			}
+		}
+
+
+		// Poll the timers of new windows.
+		//
+		if !self.done
+		{
+			self.expire( cx );
+		}
+
+		else
+		{
+			for (_, window) in self.keys.drain()
+			{
+				if let Some( event ) = window.last
+				{
+					self.ready.push_back( Backoff::Repeated{ event, count: window.count } );
+				}
+			}
+		}
+
+
+		match self.ready.pop_front()
+		{
+			Some( item )      => Poll::Ready( Some( item ) ),
+			None if self.done => Poll::Ready( None )        ,
+			None              => Poll::Pending              ,
+		}
+	}
+
+
+	fn close( &mut self )
+	{
+		self.events.close();
+	}
+}
+
+
+
+impl<Event, K, F, T> Dedup<Event, K, F, T>
+
+	where Event: Clone + 'static + Send             ,
+	      K    : Hash + Eq + Clone + Send + 'static ,
+	      T    : Timer                              ,
+{
+	// Summarize the windows that have closed and open the next one, or forget the key if it was quiet.
+	//
+	fn expire( &mut self, cx: &mut Context<'_> )
+	{
+		let (timer, max, ready) = ( &self.timer, self.max_backoff, &mut self.ready );
+
+		self.keys.retain( |_, window|
+		{
+			if window.sleep.as_mut().poll( cx ).is_pending()
+			{
+				return true;
+			}
+
+			match window.last.take()
+			{
+				None => false,
+
+				Some( event ) =>
+				{
+					ready.push_back( Backoff::Repeated{ event, count: window.count } );
+
+					window.count  = 0;
+					window.length = ( window.length * 2 ).min( max );
+					window.sleep  = Box::pin( timer.sleep( window.length ) );
+
+					true
+				}
+			}
+		});
+	}
+}
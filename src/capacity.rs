@@ -0,0 +1,69 @@
+use crate :: { import::*, ObserverId };
+
+
+/// How the buffers of the observers of a [Pharos](crate::Pharos) were used since
+/// [start_capacity_profiling](crate::Pharos::start_capacity_profiling). Use it to choose the size
+/// of [bounded channels](crate::Channel::Bounded) from what you measured, eg. in staging.
+//
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+//
+pub struct CapacityReport
+{
+	/// One entry per observer, in the order of [pending_len_per_observer](crate::Pharos::pending_len_per_observer).
+	//
+	pub observers: Vec<ObserverCapacity>,
+}
+
+
+
+/// How the buffer of one observer was used. See [CapacityReport].
+///
+/// Pharos does not read the clock, so it works with any runtime and on every platform. To get rates,
+/// pass the time since you started profiling to [send_rate](ObserverCapacity::send_rate) and
+/// [consume_rate](ObserverCapacity::consume_rate).
+//
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+//
+pub struct ObserverCapacity
+{
+	/// The observer.
+	//
+	pub id: ObserverId,
+
+	/// The number of events in it's buffer now.
+	//
+	pub buffered: usize,
+
+	/// The most events that were in it's buffer at once. A bounded channel of this size would not have
+	/// slowed down the observable.
+	//
+	pub high_water: usize,
+
+	/// The number of events sent to it.
+	//
+	pub sent: u64,
+
+	/// The number of events it consumed.
+	//
+	pub consumed: u64,
+}
+
+
+
+impl ObserverCapacity
+{
+	/// The number of events sent to this observer per second over `elapsed`.
+	//
+	pub fn send_rate( &self, elapsed: Duration ) -> f64
+	{
+		self.sent as f64 / elapsed.as_secs_f64()
+	}
+
+
+	/// The number of events this observer consumed per second over `elapsed`.
+	//
+	pub fn consume_rate( &self, elapsed: Duration ) -> f64
+	{
+		self.consumed as f64 / elapsed.as_secs_f64()
+	}
+}
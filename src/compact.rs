@@ -0,0 +1,124 @@
+use crate :: { import::*, Events, Timer, events::Adapter };
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send + PartialEq
+{
+	/// Collapse runs of adjacent equal events into one item with the number of events in the run, like the
+	/// "message repeated n times" of a logger. Unlike dropping duplicates, the count is preserved.
+	///
+	/// A run is delivered when an event arrives that is not equal to it, or when `flush` has elapsed since the
+	/// run started, so a run that doesn't end is not held back for longer than `flush`. An equal event arriving after
+	/// that starts a new run. When the observable ends the stream, the last run is delivered before the stream ends.
+	///
+	/// ```
+	/// use pharos::*;
+	/// use std::time::Duration;
+	///
+	/// # fn task( events: Events<String> ) {
+	/// let compacted = events.compact( Duration::from_secs( 1 ), async_std::task::sleep );
+	/// # }
+	/// ```
+	//
+	pub fn compact( self, flush: Duration, timer: impl Timer ) -> Events<(Event, usize)>
+	{
+		Events::from_adapter( self, |events| Compact
+		{
+			run  : None  ,
+			sleep: None  ,
+			done : false ,
+			events       ,
+			flush        ,
+			timer        ,
+		})
+	}
+}
+
+
+
+struct Compact<Event, T> where Event: Clone + 'static + Send, T: Timer
+{
+	events: Events<Event>                ,
+	flush : Duration                     ,
+	timer : T                            ,
+	run   : Option<( Event, usize )>     ,
+
+	// Runs when the current run started. None while there is a run means it's due.
+	//
+	sleep : Option<Pin<Box< T::Sleep >>> ,
+	done  : bool                         ,
+}
+
+
+
+impl<Event, T> Compact<Event, T> where Event: Clone + 'static + Send, T: Timer
+{
+	// Start a new run with `evt`, returning the previous one. The timer is polled right away, so timers
+	// that only start when they are polled count from now and wake us.
+	//
+	fn start( &mut self, evt: Event, cx: &mut Context<'_> ) -> Option<( Event, usize )>
+	{
+		let mut sleep = Box::pin( self.timer.sleep( self.flush ) );
+
+		self.sleep = match sleep.as_mut().poll( cx )
+		{
+			Poll::Ready  (()) => None         ,
+			Poll::Pending     => Some( sleep ),
+		};
+
+		self.run.replace(( evt, 1 ))
+	}
+}
+
+
+
+impl<Event, T> Adapter<(Event, usize)> for Compact<Event, T>
+
+	where Event: Clone + 'static + Send + PartialEq ,
+	      T    : Timer                              ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<(Event, usize)> >
+	{
+		while !self.done
+		{
+			match Pin::new( &mut self.events ).poll_next( cx )
+			{
+				Poll::Ready( Some(evt) ) => match &mut self.run
+				{
+					Some(( current, count )) if *current == evt => *count += 1,
+
+					Some(_) => return Poll::Ready( self.start( evt, cx ) ),
+					None    => { self.start( evt, cx ); }
+				}
+
+				Poll::Ready( None ) => self.done = true,
+				Poll::Pending       => break,
+			}
+		}
+
+
+		if self.done
+		{
+			return Poll::Ready( self.run.take() );
+		}
+
+		if self.run.is_none()
+		{
+			return Poll::Pending;
+		}
+
+		if let Some( sleep ) = &mut self.sleep
+		{
+			ready!( sleep.as_mut().poll( cx ) );
+
+			self.sleep = None;
+		}
+
+		Poll::Ready( self.run.take() )
+	}
+
+
+	fn close( &mut self )
+	{
+		self.events.close();
+	}
+}
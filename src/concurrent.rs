@@ -0,0 +1,175 @@
+use crate :: { import::*, Events, events::Adapter };
+use futures :: stream::{ FuturesOrdered, FuturesUnordered };
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Map every event with an async function, running up to `concurrency` of them at the same time. Results are
+	/// yielded as soon as they are ready, so they can come out in another order than the events came in. Use
+	/// [map_concurrent_ordered](Events::map_concurrent_ordered) to keep the order.
+	///
+	/// This is like [StreamExt::buffer_unordered](futures::StreamExt::buffer_unordered), but you keep an [Events],
+	/// so you can still [close](Events::close) it. Closing lets the futures that are already running finish,
+	/// their results are delivered before the stream ends. A `concurrency` of 0 is treated as 1.
+	///
+	/// When `concurrency` futures are running, no more events are taken from the channel, so a bounded channel
+	/// still creates back pressure.
+	///
+	/// ```
+	/// use pharos::*;
+	/// use futures::StreamExt;
+	///
+	/// # async fn lookup( id: usize ) -> String { id.to_string() }
+	/// # async fn task( events: Events<usize> ) {
+	/// let mut names = events.map_concurrent( 4, lookup );
+	///
+	/// while let Some( name ) = names.next().await
+	/// {
+	///    println!( "{}", name );
+	/// }
+	/// # }
+	/// ```
+	//
+	pub fn map_concurrent<Out, F, Fut>( self, concurrency: usize, f: F ) -> Events<Out>
+
+		where Out: Clone + 'static + Send                  ,
+		      F  : FnMut( Event ) -> Fut + Send + 'static  ,
+		      Fut: Future< Output = Out > + Send + 'static ,
+	{
+		MapConcurrent::wrap( self, concurrency, f, FuturesUnordered::new() )
+	}
+
+
+	/// Like [map_concurrent](Events::map_concurrent), but results are yielded in the order of the events. A result
+	/// that is ready waits for the ones before it, which still count towards `concurrency`.
+	//
+	pub fn map_concurrent_ordered<Out, F, Fut>( self, concurrency: usize, f: F ) -> Events<Out>
+
+		where Out: Clone + 'static + Send                  ,
+		      F  : FnMut( Event ) -> Fut + Send + 'static  ,
+		      Fut: Future< Output = Out > + Send + 'static ,
+	{
+		MapConcurrent::wrap( self, concurrency, f, FuturesOrdered::new() )
+	}
+}
+
+
+
+// The futures that are running, ordered or not.
+//
+trait Running<Fut>: Stream< Item = Fut::Output > + Unpin + Send where Fut: Future
+{
+	fn push( &mut self, fut: Fut );
+
+	fn len( &self ) -> usize;
+}
+
+
+impl<Fut> Running<Fut> for FuturesUnordered<Fut> where Fut: Future + Send
+{
+	fn push( &mut self, fut: Fut )
+	{
+		FuturesUnordered::push( self, fut );
+	}
+
+	fn len( &self ) -> usize
+	{
+		FuturesUnordered::len( self )
+	}
+}
+
+
+impl<Fut> Running<Fut> for FuturesOrdered<Fut> where Fut: Future + Send, Fut::Output: Send
+{
+	fn push( &mut self, fut: Fut )
+	{
+		self.push_back( fut );
+	}
+
+	fn len( &self ) -> usize
+	{
+		FuturesOrdered::len( self )
+	}
+}
+
+
+
+struct MapConcurrent<Event, F, R> where Event: Clone + 'static + Send
+{
+	events     : Events<Event> ,
+	f          : F             ,
+	running    : R             ,
+	concurrency: usize         ,
+	done       : bool          ,
+}
+
+
+impl<Event, F, R> MapConcurrent<Event, F, R> where Event: Clone + 'static + Send
+{
+	fn wrap<Out, Fut>( source: Events<Event>, concurrency: usize, f: F, running: R ) -> Events<Out>
+
+		where Out: Clone + 'static + Send                  ,
+		      F  : FnMut( Event ) -> Fut + Send + 'static  ,
+		      Fut: Future< Output = Out > + Send + 'static ,
+		      R  : Running<Fut> + 'static                  ,
+	{
+		Events::from_adapter( source, |events| MapConcurrent
+		{
+			concurrency: concurrency.max( 1 ),
+			done       : false               ,
+			events                           ,
+			f                                ,
+			running                          ,
+		})
+	}
+}
+
+
+
+impl<Event, Out, F, Fut, R> Adapter<Out> for MapConcurrent<Event, F, R>
+
+	where Event: Clone + 'static + Send                  ,
+	      Out  : Clone + 'static + Send                  ,
+	      F    : FnMut( Event ) -> Fut + Send + 'static  ,
+	      Fut  : Future< Output = Out > + Send + 'static ,
+	      R    : Running<Fut>                            ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Out> >
+	{
+		// Start as many as we may.
+		//
+		while !self.done && self.running.len() < self.concurrency
+		{
+			match Pin::new( &mut self.events ).poll_next( cx )
+			{
+				Poll::Ready( Some(evt) ) => self.running.push( (self.f)( evt ) ),
+				Poll::Ready( None      ) => self.done = true,
+				Poll::Pending            => break,
+			}
+		}
+
+
+		// An empty set of futures is ready with None, but we might just be waiting for events.
+		//
+		if self.running.len() == 0
+		{
+			return match self.done
+			{
+				true  => Poll::Ready( None ),
+				false => Poll::Pending      ,
+			};
+		}
+
+		match ready!( Pin::new( &mut self.running ).poll_next( cx ) )
+		{
+			Some( out ) => Poll::Ready( Some( out ) ),
+			None        => Poll::Pending             ,
+		}
+	}
+
+
+	fn close( &mut self )
+	{
+		self.events.close();
+	}
+}
@@ -0,0 +1,102 @@
+use crate :: { import::*, Events, Timer, events::Adapter };
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Deliver every event `dur` after it was received from the observable. The order of events is preserved.
+	///
+	/// ## Memory
+	///
+	/// Events are taken out of the channel as soon as they arrive, so a bounded channel no longer limits
+	/// how many events are buffered. Every event waiting for it's delay to pass is stored with it's own
+	/// timer. If the observable sends `n` events per second, about `n * dur` are held in memory at any time.
+	///
+	/// ## Closing
+	///
+	/// [close](Events::close) stops the observable from sending new events. Events that have already been
+	/// received, including those still waiting for their delay, are delivered before the stream ends.
+	//
+	pub fn delay( self, dur: Duration, timer: impl Timer ) -> Events<Event>
+	{
+		Events::from_adapter( self, |events| Delay
+		{
+			queue  : VecDeque::new() ,
+			done   : false           ,
+			events                   ,
+			timer                    ,
+			dur                      ,
+		})
+	}
+}
+
+
+
+struct Delay<Event, T> where Event: Clone + 'static + Send, T: Timer
+{
+	events: Events<Event>                   ,
+	timer : T                               ,
+	dur   : Duration                        ,
+	done  : bool                            ,
+	queue : VecDeque<( Pending<T>, Event )> ,
+}
+
+
+// The timer of an event, None once it has elapsed.
+//
+type Pending<T> = Option<Pin<Box< <T as Timer>::Sleep >>>;
+
+
+
+impl<Event, T> Adapter<Event> for Delay<Event, T>
+
+	where Event: Clone + 'static + Send ,
+	      T    : Timer                  ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Event> >
+	{
+		// Start the timer of everything that has arrived. They are polled right away, because some
+		// timers only start counting when they are polled.
+		//
+		while !self.done
+		{
+			match Pin::new( &mut self.events ).poll_next( cx )
+			{
+				Poll::Ready( Some(evt) ) =>
+				{
+					let mut sleep = Box::pin( self.timer.sleep( self.dur ) );
+					let elapsed   = sleep.as_mut().poll( cx ).is_ready();
+
+					self.queue.push_back(( if elapsed { None } else { Some( sleep ) }, evt ));
+				}
+
+				Poll::Ready( None ) => self.done = true,
+				Poll::Pending       => break,
+			}
+		}
+
+
+		// All timers have the same duration, so the front one always expires first.
+		//
+		match self.queue.front_mut()
+		{
+			Some(( sleep, _ )) =>
+			{
+				if let Some( s ) = sleep
+				{
+					ready!( s.as_mut().poll( cx ) );
+				}
+
+				Poll::Ready( self.queue.pop_front().map( |(_, evt)| evt ) )
+			}
+
+			None if self.done => Poll::Ready( None ),
+			None              => Poll::Pending      ,
+		}
+	}
+
+
+	fn close( &mut self )
+	{
+		self.events.close();
+	}
+}
@@ -0,0 +1,64 @@
+use crate :: { import::*, Events, events::Adapter };
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Stream a value computed from the events, only emitting when it changes. The first derived value is always
+	/// emitted, after that events for which `f` returns a value equal to the last emitted one are skipped.
+	///
+	/// This is the selector pattern from reactive UIs: observe a state and get notified only when the part you
+	/// care about changes.
+	//
+	pub fn derive<Out, F>( self, f: F ) -> Events<Out>
+
+		where Out: Clone + PartialEq + Send + 'static   ,
+		      F  : FnMut( Event ) -> Out + Send + 'static ,
+	{
+		Events::from_adapter( self, |events| Derive{ events, f, last: None } )
+	}
+}
+
+
+
+struct Derive<Event, Out, F> where Event: Clone + 'static + Send
+{
+	events: Events<Event> ,
+	f     : F             ,
+	last  : Option<Out>   ,
+}
+
+
+
+impl<Event, Out, F> Adapter<Out> for Derive<Event, Out, F>
+
+	where Event: Clone + 'static + Send            ,
+	      Out  : Clone + PartialEq + Send + 'static ,
+	      F    : FnMut( Event ) -> Out + Send       ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Out> >
+	{
+		loop
+		{
+			let evt = match ready!( Pin::new( &mut self.events ).poll_next( cx ) )
+			{
+				Some( evt ) => evt                      ,
+				None        => return Poll::Ready( None ),
+			};
+
+			let value = (self.f)( evt );
+
+			if self.last.as_ref() != Some( &value )
+			{
+				self.last = Some( value.clone() );
+
+				return Poll::Ready( Some( value ) );
+			}
+		}
+	}
+
+
+	fn close( &mut self )
+	{
+		self.events.close();
+	}
+}
@@ -1,4 +1,5 @@
 use crate::{ import::* };
+use futures::task::SpawnError;
 
 
 /// The error type for errors happening in `pharos`.
@@ -23,6 +24,23 @@ impl PharErr
 	{
 		&self.kind
 	}
+
+
+	/// Whether this error just means the other end of a channel went away.
+	//
+	pub(crate) fn is_disconnect( &self ) -> bool
+	{
+		match self.kind
+		{
+			ErrorKind::Closed    => true,
+			ErrorKind::SendError => self.inner.as_ref()
+
+				.and_then( |e| e.downcast_ref::<FutSendError>() )
+				.is_some_and( |e| e.is_disconnected() ),
+
+			_ => false,
+		}
+	}
 }
 
 
@@ -42,6 +60,24 @@ impl From<FutSendError> for PharErr
 	}
 }
 
+#[ cfg( feature = "regex" ) ]
+//
+impl From<regex::Error> for PharErr
+{
+	fn from( inner: regex::Error ) -> Self
+	{
+		Self { inner: Some( Box::new( inner ) ), kind: ErrorKind::InvalidPattern }
+	}
+}
+
+impl From<SpawnError> for PharErr
+{
+	fn from( inner: SpawnError ) -> Self
+	{
+		Self { inner: Some( Box::new( inner ) ), kind: ErrorKind::Spawn }
+	}
+}
+
 
 
 /// The different kind of errors that can happen when you use the `pharos` API.
@@ -58,13 +94,47 @@ pub enum ErrorKind
 	SendError,
 
 	/// The pharos object is already closed. You can no longer send messages or observe it.
-	/// This should only happen if you call [SinkExt::close](https://docs.rs/futures-preview/0.3.0-alpha.19/futures/sink/trait.SinkExt.html#method.close) on it.
+	/// This should only happen if you call [SinkExt::close](https://docs.rs/futures-preview/0.3.0-alpha.19/futures/sink/trait.SinkExt.html#method.close) on it,
+	/// or [SharedPharos::close](crate::SharedPharos::close).
 	//
 	Closed,
 
 	/// The minimum valid buffer size for [`Channel::Bounded`](crate::observable::Channel) is `1`, you sent in `0`.
 	//
 	MinChannelSizeOne,
+
+	/// You asked for a [`Channel::Priority`](crate::observable::Channel) without setting the priority of events
+	/// with [`ObserveConfig::priority`](crate::ObserveConfig::priority).
+	//
+	MissingPriority,
+
+	/// You asked for a [`Channel::Reduce`](crate::observable::Channel) without setting the function that combines
+	/// events with [`ObserveConfig::reduce`](crate::ObserveConfig::reduce).
+	//
+	MissingReducer,
+
+	/// You called [`Events::reconnect`](crate::Events::reconnect) on a stream that was not created with
+	/// [`ObserveConfig::reconnectable`](crate::ObserveConfig::reconnectable), or that was already reconnected.
+	//
+	NotReconnectable,
+
+	/// The observers have more events buffered than the limit set with
+	/// [`Pharos::set_buffer_limit`](crate::Pharos::set_buffer_limit), with [`BufferPolicy::Error`](crate::BufferPolicy::Error).
+	//
+	BufferFull,
+
+	/// The executor passed to [`Events::spawn_handler`](crate::Events::spawn_handler) failed to spawn the task.
+	//
+	Spawn,
+
+	/// The pattern passed to [`Filter::regex`](crate::Filter::regex) is not a valid regular expression.
+	//
+	InvalidPattern,
+
+	/// The code of an observer panicked while an event was delivered to it, and it was unsubscribed. This is
+	/// passed to [`Pharos::on_error`](crate::Pharos::on_error) for observers with [`PanicPolicy::Isolate`](crate::PanicPolicy::Isolate).
+	//
+	Panicked,
 }
 
 
@@ -108,9 +178,15 @@ impl fmt::Display for ErrorKind
 		match self
 		{
 			Self::SendError         => fmt::Display::fmt( "Channel closed.", f ) ,
+			Self::Closed            => fmt::Display::fmt( "The pharos object is closed. You can no longer observe or notify it.", f ) ,
 			Self::MinChannelSizeOne => fmt::Display::fmt( "The minimum valid buffer size for Channel::Bounded is 1, you send in 0.", f ) ,
-
-			_ => unreachable!(),
+			Self::MissingPriority   => fmt::Display::fmt( "Channel::Priority requires setting ObserveConfig::priority.", f ) ,
+			Self::MissingReducer    => fmt::Display::fmt( "Channel::Reduce requires setting ObserveConfig::reduce.", f ) ,
+			Self::NotReconnectable  => fmt::Display::fmt( "This stream can not be reconnected, use ObserveConfig::reconnectable.", f ) ,
+			Self::BufferFull        => fmt::Display::fmt( "The observers have more events buffered than the limit.", f ) ,
+			Self::Spawn             => fmt::Display::fmt( "Failed to spawn a task.", f ) ,
+			Self::InvalidPattern    => fmt::Display::fmt( "Invalid regular expression.", f ) ,
+			Self::Panicked          => fmt::Display::fmt( "An observer panicked and was unsubscribed.", f ) ,
 		}
 	}
 }
@@ -1,4 +1,5 @@
-use crate :: { import::*, Filter, ObserveConfig, observable::Channel, PharErr, ErrorKind };
+use crate :: { import::*, Filter, ObserveConfig, observable::{ Channel, PanicPolicy }, PharErr, ErrorKind, GroupId, ObserverId, ObserverCapacity, priority::PriorityReceiver, reduce::{ self, Reducer, ReduceReceiver }, ttl::{ Ttl, Deadline, Expire } };
+use std   :: { panic::AssertUnwindSafe };
 
 
 /// A stream of events. This is returned from [Observable::observe](crate::Observable::observe).
@@ -7,123 +8,1123 @@ use crate :: { import::*, Filter, ObserveConfig, observable::Channel, PharErr, E
 ///
 /// For pharos 0.4.0 on x64 Linux: `std::mem::size_of::<Events<_>>() == 16`
 //
+pub struct Events<Event> where Event: Clone + 'static + Send
+{
+	rx    : Receiver<Event> ,
+
+	// Events that are delivered before anything from the channel, eg. from a backfill.
+	//
+	prefix: VecDeque<Event> ,
+
+	// Only set for reconnectable observers.
+	//
+	link  : Option< Weak<SyncMutex< Link<Event> >> > ,
+
+	// Shared with the sender.
+	//
+	stats : Arc<Stats>                               ,
+}
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	pub(crate) fn new( mut config: ObserveConfig<Event> ) -> (Self, Sender<Event>)
+	{
+		let prefill  = std::mem::take( &mut config.prefill );
+		let ttl      = config.ttl.take();
+		let stats    = Arc::new( Stats::new() );
+		let (tx, rx) = Self::channel( config.channel, config.priority, config.reduce.clone(), stats.clone() );
+
+		let link = if config.reconnectable
+		{
+			let link = Link{ channel: config.channel, priority: config.priority, reduce: config.reduce, tx: None, closed: false };
+
+			Some( Arc::new( SyncMutex::new( link ) ) )
+		}
+
+		else { None };
+
+
+		let events = Self{ rx, prefix: VecDeque::new(), link: link.as_ref().map( Arc::downgrade ), stats: stats.clone() };
+
+		let mut sender = Sender
+		{
+			tx                   ,
+			filter: config.filter,
+			group : config.group ,
+			gate  : config.gate  ,
+			name  : config.name  ,
+			link                 ,
+			stats                ,
+			ttl   : None         ,
+
+			panic_policy: config.panic_policy,
+			panicked    : false              ,
+		};
+
+		sender.prefill( prefill );
+
+		let events = events.expire( ttl, &mut sender );
+
+		( events, sender )
+	}
+
+
+	// Create an observer that passes events of type `In` to a relay which sends events of type
+	// `Event` into our channel. The relay is created by `make` from the sending half of the channel
+	// and the statistics of the observer.
+	//
+	// The priority and reducer of the config are for events of type `In`, so they can not be used here.
+	//
+	pub(crate) fn with_relay<In, R>( mut config: ObserveConfig<In>, make: impl FnOnce( Tx<Event>, Arc<Stats> ) -> R ) -> (Self, Sender<In>)
+
+		where In: Clone + 'static + Send ,
+		      R : Relay<In> + 'static    ,
+	{
+		let prefill  = std::mem::take( &mut config.prefill );
+		let ttl      = config.ttl.take();
+		let stats    = Arc::new( Stats::new() );
+		let (tx, rx) = Self::channel( config.channel, None, None, stats.clone() );
+
+		let mut sender = Sender
+		{
+			tx    : Tx::Relay( Box::new( make( tx, stats.clone() ) ) ),
+			filter: config.filter                                      ,
+			group : config.group                                       ,
+			gate  : config.gate                                        ,
+			name  : config.name                                        ,
+			link  : None                                               ,
+			stats : stats.clone()                                      ,
+			ttl   : None                                               ,
+
+			panic_policy: config.panic_policy,
+			panicked    : false              ,
+		};
+
+		sender.prefill( prefill );
+
+		let events = Self{ rx, prefix: VecDeque::new(), link: None, stats }.expire( ttl, &mut sender );
+
+		( events, sender )
+	}
+
+
+	// Create a stream that is fed by a relay through the returned channel, next to the one created by
+	// with_relay. It shares `stats` with that observer.
+	//
+	pub(crate) fn with_stats( channel: Channel, stats: Arc<Stats> ) -> (Tx<Event>, Self)
+	{
+		let (tx, rx) = Self::channel( channel, None, None, stats.clone() );
+
+		( tx, Self{ rx, prefix: VecDeque::new(), link: None, stats } )
+	}
+
+
+	// Create a channel of the requested type.
+	//
+	fn channel( channel: Channel, priority: Option<fn(&Event) -> u64>, reducer: Option<Reducer<Event>>, stats: Arc<Stats> ) -> (Tx<Event>, Receiver<Event>)
+	{
+		match channel
+		{
+			Channel::Bounded( queue_size ) =>
+			{
+				let (tx, rx) = mpsc::channel( queue_size - 1 );
+
+				( Tx::Bounded( tx, stats ), Receiver::Bounded{ rx } )
+			}
+
+			Channel::Unbounded =>
+			{
+				let (tx, rx) = mpsc::unbounded();
+
+				( Tx::Unbounded( tx, stats ), Receiver::Unbounded{ rx } )
+			}
+
+			// Observable implementations must verify that the priority is set.
+			//
+			Channel::Priority( queue_size ) =>
+			{
+				let (tx, rx) = mpsc::channel( queue_size - 1 );
+				let key      = priority.unwrap_or( |_| 0 );
+
+				( Tx::Priority( tx, stats, queue_size ), Receiver::Priority{ rx: PriorityReceiver::new( rx, key, queue_size ) } )
+			}
+
+			// Observable implementations must verify that the reducer is set. Relays don't have one,
+			// they keep the latest event.
+			//
+			Channel::Reduce =>
+			{
+				let reducer  = reducer.unwrap_or_else( || Reducer::new( |_, evt| evt ) );
+				let (tx, rx) = reduce::channel( reducer, stats );
+
+				( Tx::Relay( Box::new( tx ) ), Receiver::Reduce{ rx } )
+			}
+
+			_ => unreachable!(),
+		}
+	}
+
+
+	/// Wrap a stream adapter, eg. a combinator over another [Events]. `make` creates the adapter from
+	/// `source`. The statistics of the observer remain available on the result.
+	//
+	pub(crate) fn from_adapter<In, A>( source: Events<In>, make: impl FnOnce( Events<In> ) -> A ) -> Self
+
+		where In: Clone + 'static + Send     ,
+		      A : Adapter<Event> + 'static ,
+	{
+		let stats = source.stats.clone();
+
+		Self{ rx: Receiver::Adapter{ rx: Box::new( make( source ) ) }, prefix: VecDeque::new(), link: None, stats }
+	}
+
+
+	/// Create another stream from an adapter. It shares the statistics of this observer.
+	//
+	pub(crate) fn sibling<Out>( &self, adapter: impl Adapter<Out> + 'static ) -> Events<Out>
+
+		where Out: Clone + 'static + Send
+	{
+		Events{ rx: Receiver::Adapter{ rx: Box::new( adapter ) }, prefix: VecDeque::new(), link: None, stats: self.stats.clone() }
+	}
+
+
+	/// Close this observer once `ttl` has elapsed, if there is one. The timer is started right away, so it counts
+	/// from the moment the observer subscribes, also for timers that only start when they are polled. `sender`
+	/// checks the timer as well, so it stops sending even if the consumer doesn't poll.
+	//
+	fn expire<In>( mut self, ttl: Option<Ttl>, sender: &mut Sender<In> ) -> Self
+
+		where In: Clone + 'static + Send
+	{
+		let deadline = match ttl
+		{
+			Some( ttl ) => ttl.start(),
+			None        => return self,
+		};
+
+		sender.ttl = Some( deadline.clone() );
+
+		if deadline.elapsed()
+		{
+			self.close();
+			return self;
+		}
+
+		// Reconnecting goes through the outer stream, which closes this one.
+		//
+		let link       = self.link.take();
+		let mut events = Events::from_adapter( self, |events| Expire::new( events, deadline ) );
+
+		events.link = link;
+		events
+	}
+
+
+	/// Create a stream from an adapter that doesn't wrap a single observer, eg. one that merges several. It gets
+	/// statistics of it's own.
+	//
+	pub(crate) fn detached( adapter: impl Adapter<Event> + 'static ) -> Self
+	{
+		Self::from_rx( Receiver::Adapter{ rx: Box::new( adapter ) } )
+	}
+
+
+	/// Create a stream of the events sent into a channel you control, without an observable. This is meant for
+	/// testing code that takes [Events]. `rx` can be a bounded or an unbounded receiver of
+	/// [futures::channel::mpsc](https://docs.rs/futures/0.3/futures/channel/mpsc/index.html). The stream ends when
+	/// all senders are dropped. To get a stream of events you already have, use [FromIterator](std::iter::FromIterator).
+	///
+	/// ```
+	/// use pharos::*;
+	/// use futures::{ channel::mpsc, StreamExt };
+	///
+	/// # futures::executor::block_on( async {
+	/// let (tx, rx) = mpsc::unbounded();
+	/// let events   = Events::from_receiver( rx );
+	///
+	/// tx.unbounded_send( 1 ).unwrap();
+	/// drop( tx );
+	///
+	/// assert_eq!( vec![ 1 ], events.collect::<Vec<usize>>().await );
+	/// # });
+	/// ```
+	//
+	pub fn from_receiver<R>( rx: R ) -> Self where Self: From<R>
+	{
+		rx.into()
+	}
+
+
+	/// The id of this observer, as reported in [LifecycleEvent](crate::LifecycleEvent)s.
+	//
+	pub fn id( &self ) -> ObserverId
+	{
+		self.stats.id
+	}
+
+
+	/// The number of events this observer did not receive because it's [gate](crate::ObserveConfig::gate)
+	/// was closed.
+	//
+	pub fn dropped( &self ) -> u64
+	{
+		self.stats.dropped.load( Ordering::Relaxed )
+	}
+
+
+	/// Put events in front of everything that is waiting in the channel. They will be delivered
+	/// in the order of the vector.
+	//
+	pub(crate) fn prepend( &mut self, events: Vec<Event> )
+	{
+		let mut prefix: VecDeque<Event> = events.into();
+
+		prefix.append( &mut self.prefix );
+
+		self.prefix = prefix;
+	}
+
+
+	/// Disconnect from the observable object. This way the sender will stop sending new events
+	/// and you can still continue to read any events that are still pending in the channel.
+	//
+	pub fn close( &mut self )
+	{
+		self.rx.close();
+	}
+
+
+	/// Wait for the next event for which `pred` returns true. Events that don't match are discarded, they
+	/// will not be returned by later calls. Resolves to `None` if the stream ends before a matching event arrives.
+	//
+	pub fn next_where<'a, P>( &'a mut self, mut pred: P ) -> impl Future< Output = Option<Event> > + 'a
+
+		where P: FnMut( &Event ) -> bool + 'a
+	{
+		poll_fn( move |cx| loop
+		{
+			match ready!( Pin::new( &mut *self ).poll_next( cx ) )
+			{
+				Some( evt ) if pred( &evt ) => return Poll::Ready( Some( evt ) ),
+				Some( _   )                 => continue                         ,
+				None                        => return Poll::Ready( None )       ,
+			}
+		})
+	}
+
+
+	/// Handle every event with `f` until the stream ends. `f` is awaited before the next event is taken, so
+	/// events are handled one at a time, in order.
+	///
+	/// The stream ends when the observable is dropped or closed, or when it [unsubscribes](crate::Pharos::unsubscribe)
+	/// this observer. In all cases the events that were already sent are handled first, so the future resolves
+	/// once nothing is left. This is [StreamExt::for_each](https://docs.rs/futures/0.3/futures/stream/trait.StreamExt.html#method.for_each)
+	/// with these guarantees spelled out.
+	///
+	/// ```
+	/// use pharos::*;
+	///
+	/// # async fn task( events: Events<String> ) {
+	/// events.for_each_until_closed( |line| async move
+	/// {
+	///    println!( "{}", line );
+	///
+	/// }).await;
+	///
+	/// // The observable is gone and every event has been handled.
+	/// # }
+	/// ```
+	//
+	pub async fn for_each_until_closed<F, Fut>( self, f: F )
+
+		where F  : FnMut( Event ) -> Fut ,
+		      Fut: Future< Output = () > ,
+	{
+		futures::StreamExt::for_each( self, f ).await
+	}
+
+
+	/// Close the channel and return every event that is still pending. Dropping [Events] loses the events
+	/// that are buffered, and Rust has no async drop that could hand them over. When pending events matter,
+	/// call this instead of dropping.
+	///
+	/// Returns the events the observable sent before the channel was closed, in order. Events that are still
+	/// being processed, eg. by [delay](Events::delay), are waited for.
+	///
+	/// ```
+	/// use pharos::*;
+	///
+	/// # async fn task( events: Events<String> ) {
+	/// for line in events.finish().await
+	/// {
+	///    println!( "{}", line );
+	/// }
+	/// # }
+	/// ```
+	//
+	pub async fn finish( mut self ) -> Vec<Event>
+	{
+		self.close();
+
+		futures::StreamExt::collect( self ).await
+	}
+
+
+	/// Replace the channel of a [reconnectable](crate::ObserveConfig::reconnectable) observer by a new one.
+	/// The observer keeps it's place in the observable with all it's options (filter, group, channel type, ...).
+	///
+	/// The channel of `self` is closed. You can still read the events that are pending in it, after which
+	/// it ends. The returned stream receives all events notified from now on. Events notified while no channel
+	/// was connected are lost. A [backfill](crate::ObserveConfig::backfill) does not run again.
+	///
+	/// ## Errors
+	///
+	/// - [ErrorKind::NotReconnectable] if this observer was not created with
+	///   [ObserveConfig::reconnectable](crate::ObserveConfig::reconnectable), or `self` was already reconnected.
+	/// - [ErrorKind::Closed] if the observable was closed or dropped.
+	//
+	pub fn reconnect( &mut self ) -> Result< Events<Event>, PharErr >
+	{
+		let weak = self.link.as_ref().ok_or( ErrorKind::NotReconnectable )?;
+		let arc  = weak.upgrade().ok_or( ErrorKind::Closed )?;
+
+		let mut link = arc.lock().expect( "lock link" );
+
+		if link.closed
+		{
+			return Err( ErrorKind::Closed.into() );
+		}
+
+		let (tx, rx) = Self::channel( link.channel, link.priority, link.reduce.clone(), self.stats.clone() );
+
+		// The sender sends under the lock of the link, so it picks up the new channel with the next event.
+		//
+		link.tx = Some( tx );
+		self.rx.close();
+
+		Ok( Self{ rx, prefix: VecDeque::new(), link: self.link.take(), stats: self.stats.clone() } )
+	}
+
+
+	/// Switch a [reconnectable](crate::ObserveConfig::reconnectable) observer to another type of channel, eg. from
+	/// [Channel::Bounded] to [Channel::Reduce] when the consumer can't keep up. The observer keeps it's place in the
+	/// observable with all it's other options, and later reconnects use the new channel type.
+	///
+	/// The events that are still buffered in the old channel are kept. They are delivered before the events of the
+	/// new channel, in the order the old one would have delivered them. They don't count towards the capacity of the
+	/// new channel and a [Channel::Reduce] doesn't combine them. Notifying only waits for the switch itself, never
+	/// for the consumer, and no events are lost in between.
+	///
+	/// A [Channel::Reduce] uses the function set with [ObserveConfig::reduce](crate::ObserveConfig::reduce). Without
+	/// one it keeps the latest event. Like for [reconnect](Events::reconnect), a [ttl](crate::ObserveConfig::ttl)
+	/// no longer applies afterwards.
+	///
+	/// This takes `&mut self` rather than `&self`: it replaces the channel this stream reads from, and reading
+	/// from a stream needs `&mut` already, so a shared handle would only add a lock to every poll.
+	///
+	/// ```
+	/// use pharos::*;
+	///
+	/// # async fn task( mut pharos: Pharos<usize> ) -> Result<(), PharErr> {
+	/// let opts       = ObserveConfig::default().channel( Channel::Bounded( 16 ) ).reconnectable();
+	/// let mut events = pharos.observe( opts ).await?;
+	///
+	/// // Only keep the latest event from now on.
+	/// //
+	/// events.set_channel( Channel::Reduce )?;
+	/// # Ok(()) }
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// - [ErrorKind::NotReconnectable] if this observer was not created with
+	///   [ObserveConfig::reconnectable](crate::ObserveConfig::reconnectable), or `self` was already reconnected.
+	/// - [ErrorKind::Closed] if the observable was closed or dropped.
+	/// - [ErrorKind::MinChannelSizeOne] if the queue size of `channel` is `0`.
+	/// - [ErrorKind::MissingPriority] for a [Channel::Priority] when the observer has no
+	///   [priority](crate::ObserveConfig::priority).
+	//
+	pub fn set_channel( &mut self, channel: Channel ) -> Result<(), PharErr>
+	{
+		if let Channel::Bounded( queue_size ) | Channel::Priority( queue_size ) = channel
+		{
+			if queue_size < 1 { return Err( ErrorKind::MinChannelSizeOne.into() ); }
+		}
+
+		let weak = self.link.as_ref().ok_or( ErrorKind::NotReconnectable )?;
+		let arc  = weak.upgrade().ok_or( ErrorKind::Closed )?;
+
+		let mut link = arc.lock().expect( "lock link" );
+
+		if link.closed
+		{
+			return Err( ErrorKind::Closed.into() );
+		}
+
+		if matches!( channel, Channel::Priority(_) ) && link.priority.is_none()
+		{
+			return Err( ErrorKind::MissingPriority.into() );
+		}
+
+		let (tx, rx) = Self::channel( channel, link.priority, link.reduce.clone(), self.stats.clone() );
+
+		// The sender sends under the lock of the link, so it picks up the new channel with the next event.
+		// Once closed, the old channel holds everything that was sent before the switch.
+		//
+		link.channel = channel;
+		link.tx      = Some( tx );
+
+		let mut old = std::mem::replace( &mut self.rx, rx );
+
+		old.close();
+		drop( link );
+
+		let mut cx = Context::from_waker( futures::task::noop_waker_ref() );
+
+		while let Poll::Ready( Some(evt) ) = Pin::new( &mut old ).poll_next( &mut cx )
+		{
+			if Self::keep( &self.stats, &old )
+			{
+				self.prefix.push_back( evt );
+			}
+		}
+
+		Ok(())
+	}
+
+
+	// Count an event that came out of `rx` as consumed. Returns false if the pharos has shed it, see
+	// BufferPolicy::DropOldest, so it must be skipped.
+	//
+	fn keep( stats: &Stats, rx: &Receiver<Event> ) -> bool
+	{
+		// Adapters share the stats of the events they wrap, which count for themselves.
+		//
+		if matches!( rx, Receiver::Adapter{..} )
+		{
+			return true;
+		}
+
+		if stats.take_shed()
+		{
+			return false;
+		}
+
+		stats.pop();
+
+		true
+	}
+}
+
+
+
+// We never pin project into the events, so there is no reason to require `Event: Unpin`.
+//
+impl<Event> Unpin for Events<Event> where Event: Clone + 'static + Send {}
+
+
+
+/// A stream of the events of a bounded channel you control. See [Events::from_receiver].
+//
+impl<Event> From< FutReceiver<Event> > for Events<Event> where Event: Clone + 'static + Send
+{
+	fn from( rx: FutReceiver<Event> ) -> Self
+	{
+		Self::from_rx( Receiver::Bounded{ rx } )
+	}
+}
+
+
+
+/// A stream of the events of an unbounded channel you control. See [Events::from_receiver].
+//
+impl<Event> From< FutUnboundedReceiver<Event> > for Events<Event> where Event: Clone + 'static + Send
+{
+	fn from( rx: FutUnboundedReceiver<Event> ) -> Self
+	{
+		Self::from_rx( Receiver::Unbounded{ rx } )
+	}
+}
+
+
+
+/// A stream that delivers these events and then ends, without an observable. This is meant for testing
+/// code that takes [Events].
+///
+/// ```
+/// use pharos::*;
+///
+/// let events: Events<usize> = vec![ 1, 2, 3 ].into_iter().collect();
+/// ```
+//
+impl<Event> std::iter::FromIterator<Event> for Events<Event> where Event: Clone + 'static + Send
+{
+	fn from_iter<I: IntoIterator<Item = Event>>( iter: I ) -> Self
+	{
+		let (_, rx)    = mpsc::unbounded();
+		let mut events = Self::from_rx( Receiver::Unbounded{ rx } );
+
+		events.prefix = iter.into_iter().collect();
+
+		events
+	}
+}
+
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	// An observer without an observable.
+	//
+	fn from_rx( rx: Receiver<Event> ) -> Self
+	{
+		Self{ rx, prefix: VecDeque::new(), link: None, stats: Arc::new( Stats::new() ) }
+	}
+}
+
+
+
+impl<Event> fmt::Debug for Events<Event>  where Event: 'static + Clone + Send
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		f.debug_struct( "Events" ).field( "rx", &self.rx ).finish()
+	}
+}
+
+
+
+// Deliver the prefix first, then forward to the channel.
+//
+impl<Event> Stream for Events<Event> where Event: Clone + 'static + Send
+{
+	type Item = Event;
+
+	fn poll_next( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll< Option<Self::Item> >
+	{
+		if let Some( event ) = self.prefix.pop_front()
+		{
+			return Poll::Ready( Some( event ) );
+		}
+
+		loop
+		{
+			let evt = ready!( Pin::new( &mut self.rx ).poll_next( cx ) );
+
+			if evt.is_some() && !Self::keep( &self.stats, &self.rx )
+			{
+				continue;
+			}
+
+			return Poll::Ready( evt );
+		}
+	}
+}
+
+
+
+/// The sender of the channel, with the per observer options from [ObserveConfig].
+//
+pub(crate) struct Sender<Event> where Event: Clone + 'static + Send
+{
+	tx    : Tx<Event>                               ,
+	filter: Option<Filter<Event>>                   ,
+	group : Option<GroupId>                         ,
+	gate  : Option<Arc<AtomicBool>>                 ,
+	name  : Option< Arc<str> >                      ,
+	link  : Option< Arc<SyncMutex< Link<Event> >> > ,
+	stats : Arc<Stats>                              ,
+
+	// The ttl of the observer, see ObserveConfig::ttl. A reconnected channel has none.
+	//
+	ttl   : Option< Arc<Deadline> >                 ,
+
+	// Whether the code of the observer panicked, see PanicPolicy::Isolate.
+	//
+	panic_policy: PanicPolicy ,
+	panicked    : bool        ,
+}
+
+
+/// The id and counters of an observer, shared between it's [Sender] and [Events].
+//
 #[ derive( Debug ) ]
 //
-pub struct Events<Event> where Event: Clone + 'static + Send
+pub(crate) struct Stats
+{
+	pub(crate) id      : ObserverId  ,
+	pub(crate) dropped : AtomicU64   ,
+
+	// The dropped events that were never sent, unlike the ones that were shed. Sequence numbers skip
+	// these, shed events already have one.
+	//
+	pub(crate) unsent  : AtomicU64   ,
+
+	// The number of events in the channel that have not been consumed yet.
+	//
+	pub(crate) buffered: AtomicUsize ,
+
+	// Events the receiver has to discard before yielding the next one, see BufferPolicy::DropOldest.
+	// They no longer count as buffered.
+	//
+	pub(crate) shed    : AtomicUsize ,
+
+	// A pharos waiting for the observer to consume, see Pharos::set_buffer_limit.
+	//
+	pub(crate) waker   : AtomicWaker ,
+
+	// For capacity reports, see Pharos::start_capacity_profiling.
+	//
+	pub(crate) sent      : AtomicU64   ,
+	pub(crate) consumed  : AtomicU64   ,
+	pub(crate) high_water: AtomicUsize ,
+}
+
+
+impl Stats
+{
+	pub(crate) fn new() -> Self
+	{
+		Self
+		{
+			id      : ObserverId::next()    ,
+			dropped : AtomicU64::new( 0 )   ,
+			unsent  : AtomicU64::new( 0 )   ,
+			buffered: AtomicUsize::new( 0 ) ,
+			shed    : AtomicUsize::new( 0 ) ,
+			waker   : AtomicWaker::new()    ,
+
+			sent      : AtomicU64  ::new( 0 ) ,
+			consumed  : AtomicU64  ::new( 0 ) ,
+			high_water: AtomicUsize::new( 0 ) ,
+		}
+	}
+
+
+	/// An event was put in the channel.
+	//
+	pub(crate) fn push( &self )
+	{
+		let buffered = self.buffered.fetch_add( 1, Ordering::Relaxed ) + 1;
+
+		self.sent      .fetch_add( 1       , Ordering::Relaxed );
+		self.high_water.fetch_max( buffered, Ordering::Relaxed );
+	}
+
+
+	/// An event was consumed.
+	//
+	pub(crate) fn pop( &self )
+	{
+		// Saturate, in case an event is consumed before it was counted.
+		//
+		let _ = self.buffered.fetch_update( Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub( 1 ) );
+
+		self.consumed.fetch_add( 1, Ordering::Relaxed );
+		self.waker.wake();
+	}
+
+
+	/// Drop the oldest buffered event. The pharos can't take it out of the channel, so the receiver
+	/// discards it, but it stops counting right away. Returns false if nothing is buffered.
+	//
+	pub(crate) fn shed( &self ) -> bool
+	{
+		if self.buffered.fetch_update( Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub( 1 ) ).is_err()
+		{
+			return false;
+		}
+
+		self.shed   .fetch_add( 1, Ordering::Relaxed );
+		self.dropped.fetch_add( 1, Ordering::Relaxed );
+
+		true
+	}
+
+
+	/// A buffered event was lost before it was consumed, eg. because the reducer of a
+	/// [Channel::Reduce] panicked.
+	//
+	pub(crate) fn lose( &self )
+	{
+		let _ = self.buffered.fetch_update( Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub( 1 ) );
+
+		self.dropped.fetch_add( 1, Ordering::Relaxed );
+		self.waker.wake();
+	}
+
+
+	/// Whether the receiver should discard the event it just got, see [Stats::shed].
+	//
+	pub(crate) fn take_shed( &self ) -> bool
+	{
+		self.shed.fetch_update( Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub( 1 ) ).is_ok()
+	}
+}
+
+
+/// The sending half of the different channel types.
+//
+pub(crate) enum Tx<Event> where Event: Clone + 'static + Send
 {
-	rx: Receiver<Event>,
+	Bounded  ( FutSender         <Event>, Arc<Stats> ) ,
+	Unbounded( FutUnboundedSender<Event>, Arc<Stats> ) ,
+	Relay    ( Box< dyn Relay<Event> >               ) ,
+
+	// The receiver moves events from the channel into it's heap, so the channel alone doesn't limit how
+	// many are buffered. The usize is the queue size, which limits the events in the channel and the heap.
+	//
+	Priority ( FutSender<Event>, Arc<Stats>, usize   ) ,
 }
 
 
-impl<Event> Events<Event> where Event: Clone + 'static + Send
+/// Transforms events before sending them into a channel of another type. This lets an observer receive
+/// something else than the events of the observable. Relays live in a [Sender] and must respect the
+/// contract of [Sink].
+//
+pub(crate) trait Relay<Event>: Send
+{
+	fn is_closed( &self ) -> bool;
+
+	fn poll_ready( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>;
+
+	// Returns false if the relay filtered the event out, so it doesn't count towards the reach of the
+	// notification.
+	//
+	fn start_send( &mut self, evt: Event ) -> Result<bool, PharErr>;
+
+	fn poll_flush( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>;
+
+	fn poll_close( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>;
+}
+
+
+/// Lets the [Events] of a reconnectable observer hand a new channel to it's [Sender].
+//
+struct Link<Event> where Event: Clone + 'static + Send
+{
+	channel : Channel                   ,
+	priority: Option<fn(&Event) -> u64> ,
+	reduce  : Option< Reducer<Event> >  ,
+
+	// A new channel the sender should switch to.
+	//
+	tx      : Option< Tx<Event> >       ,
+
+	// The observable is closed, so there is no use in reconnecting.
+	//
+	closed  : bool                      ,
+}
+
+
+
+
+impl<Event> Sender<Event>  where Event: Clone + 'static + Send
 {
-	pub(crate) fn new( config: ObserveConfig<Event> ) -> (Self, Sender<Event>)
+	/// Like [Sink::start_send], but returns whether the event was delivered. Relays can filter it out.
+	///
+	/// A reconnectable observer holds it's link while sending, and [Events::set_channel] and [Events::reconnect]
+	/// replace the channel under that lock. So the event either goes in the old channel before it's closed, or
+	/// in the new one.
+	//
+	pub(crate) fn deliver( &mut self, item: Event ) -> Result<bool, PharErr>
 	{
-		let (tx, rx) = match config.channel
+		let link      = self.link.clone();
+		let mut guard = link.as_ref().map( |link| link.lock().expect( "lock link" ) );
+
+		if let Some( tx ) = guard.as_mut().and_then( |link| link.tx.take() )
 		{
-			Channel::Bounded( queue_size ) =>
+			self.tx  = tx;
+			self.ttl = None;
+		}
+
+		self.send( item )
+	}
+
+
+	// Relays can run code of the observer, so this is where panics are caught for PanicPolicy::Isolate.
+	//
+	fn send( &mut self, item: Event ) -> Result<bool, PharErr>
+	{
+		if self.expired()
+		{
+			return Err( ErrorKind::Closed.into() );
+		}
+
+		if self.panic_policy == PanicPolicy::Propagate
+		{
+			return self.tx.deliver( item );
+		}
+
+		let tx = &mut self.tx;
+
+		match std::panic::catch_unwind( AssertUnwindSafe( || tx.deliver( item ) ) )
+		{
+			Ok ( res ) => res,
+
+			Err( _ ) =>
 			{
-				let (tx, rx) = mpsc::channel( queue_size - 1 );
+				self.panicked = true;
 
-				( Sender::Bounded{ tx, filter: config.filter }, Receiver::Bounded{ rx } )
+				Err( ErrorKind::Panicked.into() )
 			}
+		}
+	}
 
-			Channel::Unbounded =>
+
+	// Verify whether this observer is still around. If the observer has reconnected, this
+	// switches to the new channel.
+	//
+	pub(crate) fn is_closed( &mut self ) -> bool
+	{
+		if self.panicked
+		{
+			return true;
+		}
+
+		if self.tx.is_closed() || self.expired()
+		{
+			if let Some( tx ) = self.link.as_ref().and_then( |link| link.lock().expect( "lock link" ).tx.take() )
 			{
-				let (tx, rx) = mpsc::unbounded();
+				self.tx  = tx;
+				self.ttl = None;
+			}
+		}
+
+		self.tx.is_closed() || self.expired()
+	}
+
 
-				( Sender::Unbounded{ tx, filter: config.filter }, Receiver::Unbounded{ rx } )
+	/// Whether the ttl of this observer has elapsed. The events that were already sent are still delivered,
+	/// but it doesn't take new ones.
+	//
+	pub(crate) fn expired( &self ) -> bool
+	{
+		self.ttl.as_ref().is_some_and( |ttl| ttl.elapsed() )
+	}
+
+
+	/// Put the prefill of the config in the channel, as long as it has room. The rest is dropped.
+	//
+	fn prefill( &mut self, events: Vec<Event> )
+	{
+		let mut cx = Context::from_waker( futures::task::noop_waker_ref() );
+
+		for evt in events
+		{
+			match Pin::new( &mut self.tx ).poll_ready( &mut cx )
+			{
+				Poll::Ready( Ok(()) ) => {}
+				_                     => return,
 			}
 
-			_ => unreachable!(),
-		};
+			if Pin::new( &mut self.tx ).start_send( evt ).is_err() { return; }
+		}
+	}
 
 
-		( Self{ rx }, tx )
+	/// Whether the observer is listening, without switching channels like [is_closed](Sender::is_closed).
+	//
+	pub(crate) fn is_live( &self ) -> bool
+	{
+		!self.tx.is_closed() || self.link.as_ref().is_some_and( |link| link.lock().expect( "lock link" ).tx.is_some() )
 	}
 
 
-	/// Disconnect from the observable object. This way the sender will stop sending new events
-	/// and you can still continue to read any events that are still pending in the channel.
+	/// Whether this observer should stay registered even though it's channel is closed, because
+	/// it's [Events] are still around and might reconnect.
 	//
-	pub fn close( &mut self )
+	pub(crate) fn is_reconnectable( &self ) -> bool
 	{
-		self.rx.close();
+		self.link.as_ref().is_some_and( |link| Arc::weak_count( link ) > 0 )
 	}
-}
 
 
+	/// Check whether this sender is interested in this event. If it is but it's gate is closed, the event
+	/// is counted as dropped.
+	//
+	pub(crate) fn filter( &mut self, evt: &Event ) -> bool
+	{
+		let wanted = match &mut self.filter
+		{
+			Some(f) if self.panic_policy == PanicPolicy::Isolate =>
+			{
+				let caught = std::panic::catch_unwind( AssertUnwindSafe( || f.call(evt) ) );
 
-// Just forward
-//
-impl<Event> Stream for Events<Event> where Event: Clone + 'static + Send
-{
-	type Item = Event;
+				self.panicked = caught.is_err();
+				caught.unwrap_or( false )
+			}
 
-	fn poll_next( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll< Option<Self::Item> >
+			Some(f) => f.call(evt),
+			None    => true       ,
+		};
+
+		if wanted && self.gate.as_ref().is_some_and( |gate| !gate.load( Ordering::Relaxed ) )
+		{
+			self.count_dropped();
+
+			return false;
+		}
+
+		wanted
+	}
+
+
+	/// Whether the code of this observer panicked, see [PanicPolicy::Isolate].
+	//
+	pub(crate) fn panicked( &self ) -> bool
 	{
-		Pin::new( &mut self.rx ).poll_next( cx )
+		self.panicked
 	}
-}
 
 
+	/// Whether this observer wants every event, so [filter](Sender::filter) doesn't need to be called.
+	//
+	pub(crate) fn takes_all( &self ) -> bool
+	{
+		self.filter.is_none() && self.gate.is_none()
+	}
+
 
-/// The sender of the channel.
-/// For pharos 0.4.0 on x64 Linux: `std::mem::size_of::<Sender<_>>() == 56`
-//
-pub(crate) enum Sender<Event> where Event: Clone + 'static + Send
-{
-	Bounded  { tx: FutSender         <Event>, filter: Option<Filter<Event>> } ,
-	Unbounded{ tx: FutUnboundedSender<Event>, filter: Option<Filter<Event>> } ,
-}
+	/// The number of events in the channel of this observer that have not been consumed yet.
+	//
+	pub(crate) fn buffered( &self ) -> usize
+	{
+		self.stats.buffered.load( Ordering::Relaxed )
+	}
 
 
+	/// Wake `waker` when this observer consumes an event.
+	//
+	pub(crate) fn register_waker( &self, waker: &std::task::Waker )
+	{
+		self.stats.waker.register( waker );
+	}
 
 
-impl<Event> Sender<Event>  where Event: Clone + 'static + Send
-{
-	// Verify whether this observer is still around.
+	/// The counters of this observer for a [CapacityReport](crate::CapacityReport), since it subscribed.
 	//
-	pub(crate) fn is_closed( &self ) -> bool
+	pub(crate) fn capacity( &self ) -> ObserverCapacity
 	{
-		match self
+		ObserverCapacity
 		{
-			Sender::Bounded  { tx, .. } => tx.is_closed(),
-			Sender::Unbounded{ tx, .. } => tx.is_closed(),
+			id        : self.stats.id                                  ,
+			buffered  : self.stats.buffered  .load( Ordering::Relaxed ) ,
+			high_water: self.stats.high_water.load( Ordering::Relaxed ) ,
+			sent      : self.stats.sent      .load( Ordering::Relaxed ) ,
+			consumed  : self.stats.consumed  .load( Ordering::Relaxed ) ,
 		}
 	}
 
 
-	/// Check whether this sender is interested in this event.
+	/// Start measuring the high water mark from the current depth of the buffer.
 	//
-	pub(crate) fn filter( &mut self, evt: &Event ) -> bool
+	pub(crate) fn reset_high_water( &self )
+	{
+		self.stats.high_water.store( self.stats.buffered.load( Ordering::Relaxed ), Ordering::Relaxed );
+	}
+
+
+	/// Count an event this observer did not receive.
+	//
+	pub(crate) fn count_dropped( &self )
+	{
+		self.stats.dropped.fetch_add( 1, Ordering::Relaxed );
+		self.stats.unsent .fetch_add( 1, Ordering::Relaxed );
+	}
+
+
+	/// Drop the oldest event this observer has buffered. Returns false if it has none.
+	//
+	pub(crate) fn shed( &self ) -> bool
+	{
+		self.stats.shed()
+	}
+
+
+	/// The id of this observer.
+	//
+	pub(crate) fn id( &self ) -> ObserverId
+	{
+		self.stats.id
+	}
+
+
+	/// The name set in the config of this observer, if any.
+	//
+	pub(crate) fn name( &self ) -> Option< Arc<str> >
+	{
+		self.name.clone()
+	}
+
+
+	/// The group this observer belongs to, if any.
+	//
+	pub(crate) fn group( &self ) -> Option<GroupId>
+	{
+		self.group
+	}
+}
+
+
+
+
+impl<Event> Tx<Event> where Event: Clone + 'static + Send
+{
+	pub(crate) fn is_closed( &self ) -> bool
 	{
 		match self
 		{
-			Sender::Bounded  { filter, .. } => Self::filter_inner( filter, evt ),
-			Sender::Unbounded{ filter, .. } => Self::filter_inner( filter, evt ),
+			Tx::Bounded  ( tx, _  ) => tx.is_closed(),
+			Tx::Unbounded( tx, _  ) => tx.is_closed(),
+			Tx::Relay    ( tx     ) => tx.is_closed(),
+			Tx::Priority ( tx, .. ) => tx.is_closed(),
 		}
 	}
 
 
-	fn filter_inner( filter: &mut Option<Filter<Event>>, evt: &Event ) -> bool
+	/// Like [Sink::start_send], but returns whether the event was delivered. Relays can filter it out.
+	//
+	pub(crate) fn deliver( &mut self, item: Event ) -> Result<bool, PharErr>
 	{
-		match filter
+		match self
 		{
-			Some(f) => f.call(evt),
-			None    => true       ,
+			Tx::Bounded  ( tx, stats    ) => { Pin::new( tx ).start_send( item )?; stats.push(); Ok(true) }
+			Tx::Unbounded( tx, stats    ) => { Pin::new( tx ).start_send( item )?; stats.push(); Ok(true) }
+			Tx::Relay    ( tx           ) => tx.start_send( item )                                    ,
+			Tx::Priority ( tx, stats, _ ) => { Pin::new( tx ).start_send( item )?; stats.push(); Ok(true) }
 		}
 	}
 }
 
 
 
+/// A stream adapter that produces the events of an [Events], typically by wrapping another [Events].
+///
+/// Closing is forwarded to whatever the adapter wraps, so the observable stops sending while pending events
+/// can still be consumed. This is not a [Stream] so implementations don't have to be `Unpin`, they are boxed
+/// and never moved.
+//
+pub(crate) trait Adapter<Event>: Send
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Event> >;
+
+	fn close( &mut self );
+}
+
+
+
 /// The receiver of the channel, abstracting over different channel types.
 //
 enum Receiver<Event> where Event: Clone + 'static + Send
 {
 	Bounded  { rx: FutReceiver<Event>          } ,
 	Unbounded{ rx: FutUnboundedReceiver<Event> } ,
+	Priority { rx: PriorityReceiver<Event>     } ,
+	Reduce   { rx: ReduceReceiver<Event>       } ,
+	Adapter  { rx: Box< dyn Adapter<Event> >   } ,
 }
 
 
@@ -135,6 +1136,9 @@ impl<Event> Receiver<Event> where Event: Clone + 'static + Send
 		{
 			Receiver::Bounded  { rx } => rx.close(),
 			Receiver::Unbounded{ rx } => rx.close(),
+			Receiver::Priority { rx } => rx.close(),
+			Receiver::Reduce   { rx } => rx.close(),
+			Receiver::Adapter  { rx } => rx.close(),
 		};
 	}
 }
@@ -149,6 +1153,9 @@ impl<Event> fmt::Debug for Receiver<Event>  where Event: 'static + Clone + Send
 		{
 			Self::Bounded  {..} => write!( f, "pharos::events::Receiver::<{}>::Bounded(_)"  , type_name::<Event>() ),
 			Self::Unbounded{..} => write!( f, "pharos::events::Receiver::<{}>::Unbounded(_)", type_name::<Event>() ),
+			Self::Priority {..} => write!( f, "pharos::events::Receiver::<{}>::Priority(_)" , type_name::<Event>() ),
+			Self::Reduce   {..} => write!( f, "pharos::events::Receiver::<{}>::Reduce(_)"   , type_name::<Event>() ),
+			Self::Adapter  {..} => write!( f, "pharos::events::Receiver::<{}>::Adapter(_)"  , type_name::<Event>() ),
 		}
 	}
 }
@@ -166,13 +1173,16 @@ impl<Event> Stream for Receiver<Event> where Event: Clone + 'static + Send
 		{
 			Receiver::Bounded  { rx } => Pin::new( rx ).poll_next( cx ),
 			Receiver::Unbounded{ rx } => Pin::new( rx ).poll_next( cx ),
+			Receiver::Priority { rx } => Pin::new( rx ).poll_next( cx ),
+			Receiver::Reduce   { rx } => Pin::new( rx ).poll_next( cx ),
+			Receiver::Adapter  { rx } => rx.poll_next( cx )            ,
 		}
 	}
 }
 
 
 
-impl<Event> Sink<Event> for Sender<Event> where Event: Clone + 'static + Send
+impl<Event> Sink<Event> for Tx<Event> where Event: Clone + 'static + Send
 {
 	type Error = PharErr;
 
@@ -181,19 +1191,34 @@ impl<Event> Sink<Event> for Sender<Event> where Event: Clone + 'static + Send
 	{
 		match self.get_mut()
 		{
-			Sender::Bounded  { tx, .. } => Pin::new( tx ).poll_ready( cx ).map_err( Into::into ),
-			Sender::Unbounded{ tx, .. } => Pin::new( tx ).poll_ready( cx ).map_err( Into::into ),
+			Tx::Bounded  ( tx, _ ) => Pin::new( tx ).poll_ready( cx ).map_err( Into::into ),
+			Tx::Unbounded( tx, _ ) => Pin::new( tx ).poll_ready( cx ).map_err( Into::into ),
+			Tx::Relay    ( tx    ) => tx.poll_ready( cx )                                  ,
+
+			// The receiver wakes us when it yields an event, see Stats::pop. Check again after
+			// registering, in case it did so in between.
+			//
+			Tx::Priority ( tx, stats, queue_size ) =>
+			{
+				if !tx.is_closed() && stats.buffered.load( Ordering::Relaxed ) >= *queue_size
+				{
+					stats.waker.register( cx.waker() );
+
+					if stats.buffered.load( Ordering::Relaxed ) >= *queue_size
+					{
+						return Poll::Pending;
+					}
+				}
+
+				Pin::new( tx ).poll_ready( cx ).map_err( Into::into )
+			}
 		}
 	}
 
 
 	fn start_send( self: Pin<&mut Self>, item: Event ) -> Result<(), Self::Error>
 	{
-		match self.get_mut()
-		{
-			Sender::Bounded  { tx, .. } => Pin::new( tx ).start_send( item ).map_err( Into::into ),
-			Sender::Unbounded{ tx, .. } => Pin::new( tx ).start_send( item ).map_err( Into::into ),
-		}
+		self.get_mut().deliver( item ).map( drop )
 	}
 
 
@@ -207,21 +1232,14 @@ impl<Event> Sink<Event> for Sender<Event> where Event: Clone + 'static + Send
 	//
 	// We compensate for the error swallowing by checking `is_closed`.
 	//
-	fn poll_flush( self: Pin<&mut Self>, _cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
+	fn poll_flush( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
 	{
 		match self.get_mut()
 		{
-			Sender::Bounded  { tx, .. } =>
-			{
-				if tx.is_closed() { Poll::Ready(Err( ErrorKind::Closed.into() ))}
-				else              { Poll::Ready(Ok ( ()                       ))}
-			}
+			Tx::Relay( tx ) => tx.poll_flush( cx ),
 
-			Sender::Unbounded{ tx, .. } =>
-			{
-				if tx.is_closed() { Poll::Ready(Err( ErrorKind::Closed.into() ))}
-				else              { Poll::Ready(Ok ( ()                       ))}
-			}
+			tx if tx.is_closed() => Poll::Ready(Err( ErrorKind::Closed.into() )),
+			_                    => Poll::Ready(Ok ( ()                       )),
 		}
 	}
 
@@ -230,9 +1248,65 @@ impl<Event> Sink<Event> for Sender<Event> where Event: Clone + 'static + Send
 	{
 		match self.get_mut()
 		{
-			Sender::Bounded  { tx, .. } => Pin::new( tx ).poll_close( cx ).map_err( Into::into ),
-			Sender::Unbounded{ tx, .. } => Pin::new( tx ).poll_close( cx ).map_err( Into::into ),
+			Tx::Bounded  ( tx, _  ) => Pin::new( tx ).poll_close( cx ).map_err( Into::into ),
+			Tx::Unbounded( tx, _  ) => Pin::new( tx ).poll_close( cx ).map_err( Into::into ),
+			Tx::Relay    ( tx     ) => tx.poll_close( cx )                                  ,
+			Tx::Priority ( tx, .. ) => Pin::new( tx ).poll_close( cx ).map_err( Into::into ),
+		}
+	}
+}
+
+
+
+impl<Event> Sink<Event> for Sender<Event> where Event: Clone + 'static + Send
+{
+	type Error = PharErr;
+
+
+	fn poll_ready( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
+	{
+		let this = self.get_mut();
+
+		if this.expired()
+		{
+			return Err( ErrorKind::Closed.into() ).into();
+		}
+
+		Pin::new( &mut this.tx ).poll_ready( cx )
+	}
+
+
+	fn start_send( self: Pin<&mut Self>, item: Event ) -> Result<(), Self::Error>
+	{
+		self.get_mut().deliver( item ).map( drop )
+	}
+
+
+	// Switch to a new channel first if the observer has reconnected.
+	//
+	fn poll_flush( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
+	{
+		let this = self.get_mut();
+
+		this.is_closed();
+
+		Pin::new( &mut this.tx ).poll_flush( cx )
+	}
+
+
+	fn poll_close( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
+	{
+		let this = self.get_mut();
+
+		if let Some( link ) = &this.link
+		{
+			let mut link = link.lock().expect( "lock link" );
+
+			link.closed = true;
+			link.tx     = None;
 		}
+
+		Pin::new( &mut this.tx ).poll_close( cx )
 	}
 }
 
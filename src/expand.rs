@@ -0,0 +1,120 @@
+use crate :: { import::*, Pharos, PharErr, Events, ObserveConfig, events::{ Tx, Relay } };
+
+
+impl<Event> Pharos<Event> where Event: Clone + 'static + Send
+{
+	/// Observe with a function that turns every event into any number of events of another type, eg. to split
+	/// a compound event into it's parts. The filter of `options` is applied to the original event, before `expand`.
+	///
+	/// Items are delivered in the order they are produced by `expand`, and all items of an event are delivered
+	/// before the items of the next event.
+	///
+	/// Every item is sent individually. When a [bounded channel](crate::Channel::Bounded) can not hold all items
+	/// produced for an event, the remaining ones are kept by the pharos and notifying (the future returned by
+	/// [SinkExt::send](https://docs.rs/futures-preview/0.3.0-alpha.19/futures/sink/trait.SinkExt.html#method.send))
+	/// will not resolve before the observer has read enough events to make room for them all. When the pharos
+	/// is closed, items that did not fit in the channel are dropped.
+	///
+	/// The options for [priority](crate::ObserveConfig::priority), [backfill](crate::ObserveConfig::backfill)
+	/// and [reconnecting](crate::ObserveConfig::reconnectable) are about events of the observable, so they are
+	/// ignored here. A [Channel::Priority](crate::Channel::Priority) delivers items in order.
+	//
+	pub async fn observe_expand<Out, F>( &mut self, options: ObserveConfig<Event>, expand: F ) -> Result< Events<Out>, PharErr >
+
+		where Out: Clone + 'static + Send                     ,
+		      F  : FnMut( &Event ) -> Vec<Out> + Send + 'static ,
+	{
+		self.check( &options )?;
+
+		let (events, sender) = Events::with_relay( options, |tx, _| Expand{ tx, expand, pending: VecDeque::new() } );
+
+		self.register( sender );
+
+		Ok( events )
+	}
+}
+
+
+
+struct Expand<Out, F> where Out: Clone + 'static + Send
+{
+	tx     : Tx<Out>       ,
+	expand : F             ,
+
+	// Items that still need to go into the channel.
+	//
+	pending: VecDeque<Out> ,
+}
+
+
+impl<Out, F> Expand<Out, F> where Out: Clone + 'static + Send
+{
+	// Send pending items while the channel has room.
+	//
+	fn drain( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		while !self.pending.is_empty()
+		{
+			ready!( Pin::new( &mut self.tx ).poll_ready( cx ) )?;
+
+			let item = self.pending.pop_front().expect( "pending is not empty" );
+
+			Pin::new( &mut self.tx ).start_send( item )?;
+		}
+
+		Ok(()).into()
+	}
+}
+
+
+impl<Event, Out, F> Relay<Event> for Expand<Out, F>
+
+	where Out: Clone + 'static + Send                     ,
+	      F  : FnMut( &Event ) -> Vec<Out> + Send + 'static ,
+{
+	fn is_closed( &self ) -> bool
+	{
+		self.tx.is_closed()
+	}
+
+
+	fn poll_ready( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		self.drain( cx )
+	}
+
+
+	// An event that expands to nothing doesn't reach the observer.
+	//
+	fn start_send( &mut self, evt: Event ) -> Result<bool, PharErr>
+	{
+		let before = self.pending.len();
+
+		self.pending.extend( (self.expand)( &evt ) );
+
+		Ok( self.pending.len() > before )
+	}
+
+
+	fn poll_flush( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		ready!( self.drain( cx ) )?;
+
+		Pin::new( &mut self.tx ).poll_flush( cx )
+	}
+
+
+	// Don't wait for the observer to make room, the pharos is going away.
+	//
+	fn poll_close( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		if let Poll::Ready( Err(e) ) = self.drain( cx )
+		{
+			return Err( e ).into();
+		}
+
+		self.pending.clear();
+
+		Pin::new( &mut self.tx ).poll_close( cx )
+	}
+}
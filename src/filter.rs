@@ -1,4 +1,5 @@
 use crate :: { import::* };
+use std   :: { any::Any };
 
 /// Predicate for filtering events.
 ///
@@ -52,11 +53,83 @@ pub enum Filter<Event>
 	/// A boxed closure to a predicate to filter events.
 	//
 	Closure( Box<dyn FnMut(&Event) -> bool + Send> ),
+
+	/// A predicate that gets a shared context next to the event. See [Filter::with_context].
+	//
+	Context( Arc<dyn Any + Send + Sync>, ContextFn<Event> ),
 }
 
 
+/// The predicate of [Filter::Context]. The context is passed as [Any] so a filter does not need
+/// a type parameter for it.
+//
+pub(crate) type ContextFn<Event> = Box< dyn Fn( &Event, &dyn Any ) -> bool + Send >;
+
+
 impl<Event> Filter<Event>  where Event: Clone + 'static + Send
 {
+	/// Filter with a predicate that gets `ctx` next to every event, eg. the current permissions of a user.
+	/// The filter holds on to `ctx`, so when it's changed from elsewhere, through interior mutability, the
+	/// next event is filtered with the new value.
+	///
+	/// ```
+	/// use pharos::*;
+	/// use std::sync::{ Arc, RwLock };
+	///
+	/// struct Permissions { admin: bool }
+	///
+	/// let perms  = Arc::new( RwLock::new( Permissions{ admin: false } ) );
+	/// let filter = Filter::with_context( perms.clone(), |evt: &String, perms: &RwLock<Permissions>|
+	/// {
+	///    perms.read().unwrap().admin || !evt.starts_with( "admin" )
+	/// });
+	///
+	/// // Later, all events are let through.
+	/// //
+	/// perms.write().unwrap().admin = true;
+	/// ```
+	//
+	pub fn with_context<Ctx>( ctx: Arc<Ctx>, f: impl Fn( &Event, &Ctx ) -> bool + Send + 'static ) -> Self
+
+		where Ctx: Any + Send + Sync
+	{
+		let pred = move |evt: &Event, ctx: &dyn Any|
+		{
+			f( evt, ctx.downcast_ref::<Ctx>().expect( "context of filter has the type it was created with" ) )
+		};
+
+		Self::Context( ctx, Box::new( pred ) )
+	}
+
+
+	/// Let through the events for which the text returned by `extract` matches `pattern`, eg. to route log
+	/// messages. The pattern is compiled here, so filtering only has to match it. Requires the `regex` feature.
+	///
+	/// ## Errors
+	///
+	/// [ErrorKind::InvalidPattern](crate::ErrorKind::InvalidPattern) if `pattern` is not a valid
+	/// [regular expression](https://docs.rs/regex).
+	///
+	/// ```
+	/// use pharos::*;
+	///
+	/// #[ derive( Clone ) ] struct Log { msg: String }
+	///
+	/// let filter = Filter::regex( |log: &Log| &log.msg, r"^(error|warn):" ).expect( "valid pattern" );
+	/// ```
+	//
+	#[ cfg( feature = "regex" ) ]
+	//
+	pub fn regex<F>( extract: F, pattern: &str ) -> Result<Self, crate::PharErr>
+
+		where F: Fn( &Event ) -> &str + Send + 'static
+	{
+		let regex = regex::Regex::new( pattern )?;
+
+		Ok( Self::Closure( Box::new( move |evt| regex.is_match( extract( evt ) ) ) ) )
+	}
+
+
 	/// Invoke the predicate.
 	//
 	pub(crate) fn call( &mut self, evt: &Event ) -> bool
@@ -65,6 +138,8 @@ impl<Event> Filter<Event>  where Event: Clone + 'static + Send
 		{
 			Self::Pointer(f) => f(evt),
 			Self::Closure(f) => f(evt),
+
+			Self::Context( ctx, f ) => f( evt, &**ctx ),
 		}
 	}
 }
@@ -76,8 +151,9 @@ impl<Event> fmt::Debug for Filter<Event>  where Event: Clone + 'static + Send
 	{
 		match self
 		{
-			Self::Pointer(_) => write!( f, "pharos::Filter<{}>::Pointer(_)", type_name::<Event>() ),
-			Self::Closure(_) => write!( f, "pharos::Filter<{}>::Closure(_)", type_name::<Event>() ),
+			Self::Pointer(_)  => write!( f, "pharos::Filter<{}>::Pointer(_)", type_name::<Event>() ),
+			Self::Closure(_)  => write!( f, "pharos::Filter<{}>::Closure(_)", type_name::<Event>() ),
+			Self::Context(..) => write!( f, "pharos::Filter<{}>::Context(_)", type_name::<Event>() ),
 		}
 
 	}
@@ -97,8 +173,10 @@ mod tests
 	{
 		let f = Filter::Pointer(           |b| *b   );
 		let g = Filter::Closure( Box::new( |b| *b ) );
+		let h = Filter::with_context( Arc::new( true ), |b, c| *b && *c );
 
 		assert_eq!( "pharos::Filter<bool>::Pointer(_)", &format!( "{:?}", f ) );
 		assert_eq!( "pharos::Filter<bool>::Closure(_)", &format!( "{:?}", g ) );
+		assert_eq!( "pharos::Filter<bool>::Context(_)", &format!( "{:?}", h ) );
 	}
 }
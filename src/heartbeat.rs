@@ -0,0 +1,89 @@
+use crate :: { import::*, Events, Timer, events::Adapter };
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Yield an event created by `make_beat` whenever no event arrived for `interval`. This lets you tell
+	/// a quiet observable from a stalled one, eg. for watchdog logic.
+	///
+	/// The interval starts when the stream is first polled, and starts over after every event it yields,
+	/// real or heartbeat. So while events keep arriving faster than `interval`, no heartbeat is produced.
+	/// When the observable ends the stream, heartbeats stop and the stream ends as well.
+	///
+	/// ```
+	/// use pharos::*;
+	/// use std::time::Duration;
+	///
+	/// #[ derive( Clone ) ] enum Msg { Data( Vec<u8> ), Beat }
+	///
+	/// # fn task( events: Events<Msg> ) {
+	/// let events = events.with_heartbeat( Duration::from_secs( 5 ), || Msg::Beat, async_std::task::sleep );
+	/// # }
+	/// ```
+	//
+	pub fn with_heartbeat<B>( self, interval: Duration, make_beat: B, timer: impl Timer ) -> Events<Event>
+
+		where B: Fn() -> Event + Send + 'static
+	{
+		Events::from_adapter( self, |events| Heartbeat
+		{
+			sleep: None ,
+			events      ,
+			interval    ,
+			make_beat   ,
+			timer       ,
+		})
+	}
+}
+
+
+
+struct Heartbeat<Event, B, T> where Event: Clone + 'static + Send, T: Timer
+{
+	events   : Events<Event>                ,
+	interval : Duration                     ,
+	make_beat: B                            ,
+	timer    : T                            ,
+	sleep    : Option<Pin<Box< T::Sleep >>> ,
+}
+
+
+
+impl<Event, B, T> Adapter<Event> for Heartbeat<Event, B, T>
+
+	where Event: Clone + 'static + Send         ,
+	      B    : Fn() -> Event + Send + 'static ,
+	      T    : Timer                          ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Event> >
+	{
+		match Pin::new( &mut self.events ).poll_next( cx )
+		{
+			Poll::Ready( Some(evt) ) =>
+			{
+				self.sleep = None;
+				return Poll::Ready( Some( evt ) );
+			}
+
+			Poll::Ready( None ) => return Poll::Ready( None ),
+			Poll::Pending       => {}
+		}
+
+
+		let (timer, interval) = ( &self.timer, self.interval );
+
+		let sleep = self.sleep.get_or_insert_with( || Box::pin( timer.sleep( interval ) ) );
+
+		ready!( sleep.as_mut().poll( cx ) );
+
+		self.sleep = None;
+
+		Poll::Ready( Some( (self.make_beat)() ) )
+	}
+
+
+	fn close( &mut self )
+	{
+		self.events.close();
+	}
+}
@@ -0,0 +1,134 @@
+use crate :: { import::*, Pharos, SharedPharos, PharErr, ErrorKind, Events, ObserveConfig };
+
+
+/// An application wide event bus. The hub owns the observers, [HubHandle]s let any task notify and subscribe.
+///
+/// This is a [SharedPharos] with the ownership made explicit: handles don't keep the bus alive. When the hub
+/// is dropped or [closed](Hub::close), all subscriptions end after delivering their pending events and the
+/// handles return [ErrorKind::Closed]. So create the hub where your application sets up and hand out handles.
+///
+/// ```
+/// use pharos::*;
+/// use futures::StreamExt;
+///
+/// # futures::executor::block_on( async {
+/// let hub    = Hub::<String>::default();
+/// let handle = hub.handle();
+///
+/// let mut events = handle.subscribe( ObserveConfig::default() ).await.unwrap();
+///
+/// // Send the handle to any task, it's cheap to clone.
+/// //
+/// handle.clone().notify( "started".to_string() ).await.unwrap();
+///
+/// assert_eq!( Some( "started".to_string() ), events.next().await );
+/// # });
+/// ```
+//
+#[ derive( Debug ) ]
+//
+pub struct Hub<Event> where Event: 'static + Clone + Send
+{
+	pharos: SharedPharos<Event>,
+}
+
+
+
+impl<Event> Hub<Event> where Event: 'static + Clone + Send
+{
+	/// Create a hub around `pharos`, eg. to set options like [Pharos::set_fair] before sharing it.
+	//
+	pub fn new( pharos: Pharos<Event> ) -> Self
+	{
+		Self{ pharos: SharedPharos::new( pharos ) }
+	}
+
+
+	/// Get a handle to notify and subscribe from other tasks.
+	//
+	pub fn handle( &self ) -> HubHandle<Event>
+	{
+		HubHandle{ pharos: Arc::downgrade( &self.pharos.pharos ) }
+	}
+
+
+	/// Close the bus. Subscriptions end after delivering their pending events. See [SharedPharos::close].
+	//
+	pub async fn close( &self ) -> Result<(), PharErr>
+	{
+		self.pharos.close().await
+	}
+}
+
+
+
+impl<Event> Default for Hub<Event> where Event: 'static + Clone + Send
+{
+	fn default() -> Self
+	{
+		Self::new( Pharos::default() )
+	}
+}
+
+
+
+/// Lets any task notify and subscribe to a [Hub]. Cheap to clone.
+//
+pub struct HubHandle<Event> where Event: 'static + Clone + Send
+{
+	pharos: Weak<Mutex< Pharos<Event> >>,
+}
+
+
+
+impl<Event> HubHandle<Event> where Event: 'static + Clone + Send
+{
+	/// Notify all subscribers, returning how many accepted the event. See [Pharos::notify].
+	///
+	/// Returns [ErrorKind::Closed] if the hub was closed or dropped.
+	//
+	pub async fn notify( &self, evt: Event ) -> Result<usize, PharErr>
+	{
+		self.hub()?.notify( evt ).await
+	}
+
+
+	/// Subscribe to the events of the hub.
+	///
+	/// Returns [ErrorKind::Closed] if the hub was closed or dropped.
+	//
+	pub async fn subscribe( &self, options: ObserveConfig<Event> ) -> Result<Events<Event>, PharErr>
+	{
+		self.hub()?.observe_shared( options ).await
+	}
+
+
+	fn hub( &self ) -> Result< SharedPharos<Event>, PharErr >
+	{
+		match self.pharos.upgrade()
+		{
+			Some( pharos ) => Ok( SharedPharos{ pharos } )      ,
+			None           => Err( ErrorKind::Closed.into() ) ,
+		}
+	}
+}
+
+
+
+impl<Event> Clone for HubHandle<Event> where Event: 'static + Clone + Send
+{
+	fn clone( &self ) -> Self
+	{
+		Self{ pharos: self.pharos.clone() }
+	}
+}
+
+
+
+impl<Event> fmt::Debug for HubHandle<Event> where Event: 'static + Clone + Send
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		write!( f, "pharos::HubHandle<{}>", type_name::<Event>() )
+	}
+}
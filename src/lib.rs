@@ -1,6 +1,6 @@
 #![ cfg_attr( nightly, feature( doc_cfg ) ) ]
 #![ cfg_attr( nightly, cfg_attr( nightly, doc = include_str!("../README.md") ) )]
-#![ doc = "" ] // empty doc line to handle missing doc warning when the feature is missing.
+#![ cfg_attr( not( nightly ), doc = "Observer pattern which generates a futures 0.3 stream of events. See the README for a guide." ) ]
 
 #![ doc    ( html_root_url = "https://docs.rs/pharos" ) ]
 #![ deny   ( missing_docs                             ) ]
@@ -23,33 +23,81 @@
 )]
 
 
+mod aggregate     ;
+mod backoff       ;
+mod capacity      ;
+mod compact       ;
+mod delay         ;
+mod derive        ;
 mod error         ;
 mod events        ;
+mod expand        ;
 mod observable    ;
+mod observer_set  ;
 mod pharos        ;
+mod partition     ;
 mod filter        ;
+mod heartbeat     ;
+mod hub           ;
+mod concurrent    ;
+mod lifecycle     ;
+mod lossy         ;
+mod merge_dedup   ;
+mod priority      ;
+mod reduce        ;
+mod results       ;
+mod seq           ;
+mod select        ;
 mod shared_pharos ;
+mod stale         ;
+mod spawn         ;
+mod timer         ;
+mod ttl           ;
+mod variant       ;
+mod with_output   ;
+
+#[ cfg( feature = "tokio-stream" ) ]
+//
+mod tokio_events  ;
 
 
 
 pub use
 {
-	self::pharos :: { Pharos                                              } ,
-	filter       :: { Filter                                              } ,
-	observable   :: { Observable, ObservableLocal, ObserveConfig, Channel } ,
-	events       :: { Events                                              } ,
-	error        :: { PharErr, ErrorKind                                  } ,
-	shared_pharos:: { SharedPharos                                        } ,
+	self::pharos :: { Pharos, BufferPolicy                                                                 } ,
+	filter       :: { Filter                                                                               } ,
+	observable   :: { Observable, ObservableLocal, ObserveConfig, Channel, SyncEvent, GroupId, PanicPolicy } ,
+	events       :: { Events                                                                               } ,
+	lifecycle    :: { LifecycleEvent, ObserverId, UnsubscribeReason                                        } ,
+	observer_set :: { ObserverSet                                                                          } ,
+	partition    :: { Partition                                                                            } ,
+	error        :: { PharErr, ErrorKind                                                                   } ,
+	backoff      :: { Backoff                                                                              } ,
+	capacity     :: { CapacityReport, ObserverCapacity                                                     } ,
+	aggregate    :: { Aggregate                                                                            } ,
+	timer        :: { Timer                                                                                } ,
+	lossy        :: { Lossy                                                                                } ,
+	seq          :: { Seq                                                                                  } ,
+	hub          :: { Hub, HubHandle                                                                       } ,
+	shared_pharos:: { SharedPharos                                                                         } ,
 };
 
+#[ cfg( feature = "tokio-stream" ) ]
+//
+pub use tokio_events::TokioEvents;
+
 
 mod import
 {
 	pub(crate) use
 	{
-		std            :: { fmt, error::Error as ErrorTrait, ops::Deref, any::type_name  } ,
-		std            :: { task::{ Poll, Context }, pin::Pin, future::Future, sync::Arc } ,
-		futures        :: { Stream, Sink, SinkExt, ready, future::FutureExt, lock::Mutex } ,
+		std            :: { fmt, error::Error as ErrorTrait, ops::Deref, any::type_name    } ,
+		std            :: { task::{ Poll, Context }, pin::Pin, future::Future, sync::Arc   } ,
+		std            :: { sync::{ Weak, Mutex as SyncMutex }                             } ,
+		std            :: { sync::atomic::{ AtomicBool, AtomicU64, AtomicUsize, Ordering } } ,
+		std            :: { collections::VecDeque, time::Duration                          } ,
+		futures        :: { Stream, Sink, SinkExt, ready, lock::Mutex                      } ,
+		futures        :: { future::{ FutureExt, BoxFuture, poll_fn }, task::AtomicWaker   } ,
 
 		futures::channel::mpsc::
 		{
@@ -66,8 +114,8 @@ mod import
 	//
 	pub(crate) use
 	{
-		assert_matches :: { assert_matches                      } ,
-		futures        :: { future::poll_fn, executor::block_on } ,
+		assert_matches :: { assert_matches     } ,
+		futures        :: { executor::block_on } ,
 	};
 }
 
@@ -0,0 +1,138 @@
+use crate :: { import::*, Events, Channel, events::{ Tx, Stats } };
+
+
+/// Identifies an observer in [LifecycleEvent]s. Every observer gets a new one, they are unique within
+/// the process. Ask an [Events] for it's id with [Events::id].
+//
+#[ derive( Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash ) ]
+//
+pub struct ObserverId( u64 );
+
+
+impl ObserverId
+{
+	pub(crate) fn next() -> Self
+	{
+		static NEXT: AtomicU64 = AtomicU64::new( 0 );
+
+		Self( NEXT.fetch_add( 1, Ordering::Relaxed ) )
+	}
+}
+
+
+impl fmt::Display for ObserverId
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		write!( f, "{}", self.0 )
+	}
+}
+
+
+
+/// An observer subscribed to or left an observable. See [Pharos::lifecycle_events](crate::Pharos::lifecycle_events).
+//
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+//
+pub enum LifecycleEvent
+{
+	/// A new observer, with the [name](crate::ObserveConfig::name) it was given, if any.
+	//
+	Subscribed
+	{
+		/// The new observer.
+		//
+		id: ObserverId,
+
+		/// The name set in it's config.
+		//
+		name: Option< Arc<str> >,
+	},
+
+	/// An observer no longer receives events.
+	//
+	Unsubscribed
+	{
+		/// The observer that left.
+		//
+		id: ObserverId,
+
+		/// Why it left.
+		//
+		reason: UnsubscribeReason,
+	},
+}
+
+
+
+/// Why an observer left. See [LifecycleEvent::Unsubscribed].
+//
+#[ derive( Debug, Copy, Clone, PartialEq, Eq ) ]
+//
+pub enum UnsubscribeReason
+{
+	/// The observer closed or dropped it's [Events].
+	//
+	Disconnected,
+
+	/// The observable was closed.
+	//
+	Closed,
+
+	/// The observer was swapped out with [Pharos::swap_observers](crate::Pharos::swap_observers).
+	//
+	Swapped,
+
+	/// The observer was removed with [Pharos::unsubscribe](crate::Pharos::unsubscribe).
+	//
+	Removed,
+
+	/// The code of the observer panicked, see [PanicPolicy::Isolate](crate::PanicPolicy::Isolate).
+	//
+	Panicked,
+
+	/// The [ttl](crate::ObserveConfig::ttl) of the observer has elapsed.
+	//
+	Expired,
+}
+
+
+
+/// The observers of the lifecycle of an observable. The listeners are behind a lock so one can subscribe
+/// through a shared reference to the observable.
+//
+#[ derive( Default ) ]
+//
+pub(crate) struct Lifecycle
+{
+	listeners: SyncMutex< Vec< Tx<LifecycleEvent> > >,
+}
+
+
+impl Lifecycle
+{
+	pub(crate) fn observe( &self ) -> Events<LifecycleEvent>
+	{
+		let (tx, events) = Events::with_stats( Channel::Unbounded, Arc::new( Stats::new() ) );
+
+		self.listeners.lock().expect( "lock lifecycle" ).push( tx );
+
+		events
+	}
+
+
+	// The channels are unbounded, so we never have to wait. Listeners that went away are removed.
+	//
+	pub(crate) fn notify( &self, evt: LifecycleEvent )
+	{
+		self.listeners.lock().expect( "lock lifecycle" ).retain_mut( |tx| Pin::new( tx ).start_send( evt.clone() ).is_ok() );
+	}
+
+
+	/// End the streams of all listeners.
+	//
+	pub(crate) fn close( &self )
+	{
+		self.listeners.lock().expect( "lock lifecycle" ).clear();
+	}
+}
@@ -0,0 +1,166 @@
+use crate :: { import::*, Pharos, PharErr, Events, ObserveConfig, events::{ Tx, Relay, Stats } };
+
+
+/// An item of [Pharos::observe_lossy]: either an event or the number of events that were lost right before
+/// the next item.
+//
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+//
+pub enum Lossy<Event>
+{
+	/// An event of the observable.
+	//
+	Event( Event ),
+
+	/// This many events were dropped for this observer since the previous item.
+	//
+	Lag( u64 ),
+}
+
+
+
+impl<Event> Pharos<Event> where Event: Clone + 'static + Send
+{
+	/// Observe with an in-band signal for lost events. Whenever events were dropped for this observer, eg. by
+	/// it's [gate](crate::ObserveConfig::gate) or by a [buffer limit](Pharos::set_buffer_limit) with
+	/// [BufferPolicy::DropNewest](crate::BufferPolicy::DropNewest), the next event that gets through is preceded by a
+	/// [Lossy::Lag] with the number of events that were lost. So the consumer learns about a gap exactly where it
+	/// happened, rather than by checking [dropped](Events::dropped). When the pharos is closed, events lost since the
+	/// last item are reported by a final [Lossy::Lag].
+	///
+	/// On a [bounded channel](crate::Channel::Bounded), the [Lossy::Lag] takes a slot like any other item. If there
+	/// is no room left for the event that follows it, the pharos keeps it and notifying waits until the observer
+	/// has made room, like for [observe_expand](Pharos::observe_expand).
+	///
+	/// Like for [observe_expand](Pharos::observe_expand), the options for priority, backfill and reconnecting
+	/// are ignored.
+	//
+	pub async fn observe_lossy( &mut self, options: ObserveConfig<Event> ) -> Result< Events<Lossy<Event>>, PharErr >
+	{
+		self.check( &options )?;
+
+		let (events, sender) = Events::with_relay( options, |tx, stats| Lag{ tx, stats, reported: 0, pending: None } );
+
+		self.register( sender );
+
+		Ok( events )
+	}
+}
+
+
+
+struct Lag<Event> where Event: Clone + 'static + Send
+{
+	tx      : Tx<Lossy<Event>>     ,
+	stats   : Arc<Stats>           ,
+
+	// The number of dropped events that has been reported to the observer.
+	//
+	reported: u64                  ,
+
+	// An event that did not fit in the channel after it's lag.
+	//
+	pending : Option<Lossy<Event>> ,
+}
+
+
+
+impl<Event> Lag<Event> where Event: Clone + 'static + Send
+{
+	// Dropped events that have not been reported yet.
+	//
+	fn lag( &mut self ) -> u64
+	{
+		let dropped = self.stats.dropped.load( Ordering::Relaxed );
+		let lag     = dropped - self.reported;
+
+		self.reported = dropped;
+
+		lag
+	}
+
+
+	// Send the pending event once the channel has room.
+	//
+	fn drain( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		if self.pending.is_some()
+		{
+			ready!( Pin::new( &mut self.tx ).poll_ready( cx ) )?;
+
+			let item = self.pending.take().expect( "pending is some" );
+
+			Pin::new( &mut self.tx ).start_send( item )?;
+		}
+
+		Ok(()).into()
+	}
+}
+
+
+
+impl<Event> Relay<Event> for Lag<Event> where Event: Clone + 'static + Send
+{
+	fn is_closed( &self ) -> bool
+	{
+		self.tx.is_closed()
+	}
+
+
+	fn poll_ready( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		ready!( self.drain( cx ) )?;
+
+		Pin::new( &mut self.tx ).poll_ready( cx )
+	}
+
+
+	fn start_send( &mut self, evt: Event ) -> Result<bool, PharErr>
+	{
+		match self.lag()
+		{
+			0 => self.tx.deliver( Lossy::Event( evt ) ),
+
+			lag =>
+			{
+				self.pending = Some( Lossy::Event( evt ) );
+
+				self.tx.deliver( Lossy::Lag( lag ) )
+			}
+		}
+	}
+
+
+	fn poll_flush( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		ready!( self.drain( cx ) )?;
+
+		Pin::new( &mut self.tx ).poll_flush( cx )
+	}
+
+
+	// Report what was lost since the last item if there is room. Don't wait for the observer,
+	// the pharos is going away.
+	//
+	fn poll_close( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		if let Poll::Ready( Err(e) ) = self.drain( cx )
+		{
+			return Err( e ).into();
+		}
+
+		if self.pending.is_none() && Pin::new( &mut self.tx ).poll_ready( cx ).is_ready()
+		{
+			let lag = self.lag();
+
+			if lag > 0
+			{
+				let _ = Pin::new( &mut self.tx ).start_send( Lossy::Lag( lag ) );
+			}
+		}
+
+		self.pending = None;
+
+		Pin::new( &mut self.tx ).poll_close( cx )
+	}
+}
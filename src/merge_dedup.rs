@@ -0,0 +1,158 @@
+use crate :: { import::*, Events, Timer, events::Adapter };
+use std   :: { collections::HashSet, hash::Hash };
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Merge redundant feeds of the same events into one stream where every logical event appears once. Events
+	/// are identified by the key returned by `key`.
+	///
+	/// The streams are merged like [select_labeled](Events::select_labeled). The first event for a key, from any
+	/// of the streams, is delivered and other events with that key are suppressed until `window` has elapsed
+	/// since. After that, the key is forgotten and the next event with it is delivered again. Choose `window`
+	/// long enough to cover the delay between the feeds, and shorter than the time after which a key can be reused.
+	///
+	/// Windows are timed when events are read, so a consumer that falls behind by more than `window` can see
+	/// duplicates. Every key seen during the last `window` is kept with a timer from `timer`. The stream ends when all
+	/// streams have ended. [close](Events::close) closes all of them.
+	///
+	/// ```
+	/// use pharos::*;
+	/// use std::time::Duration;
+	///
+	/// #[ derive( Clone ) ] struct Trade { id: u64, price: u64 }
+	///
+	/// # fn task( primary: Events<Trade>, backup: Events<Trade> ) {
+	/// let trades = Events::merge_dedup
+	/// (
+	///    vec![ primary, backup ]  ,
+	///    |trade| trade.id         ,
+	///    Duration::from_secs( 5 ) ,
+	///    async_std::task::sleep   ,
+	/// );
+	/// # }
+	/// ```
+	//
+	pub fn merge_dedup<K, F>( streams: Vec< Events<Event> >, key: F, window: Duration, timer: impl Timer ) -> Events<Event>
+
+		where K: Hash + Eq + Clone + Send + 'static    ,
+		      F: FnMut( &Event ) -> K + Send + 'static ,
+	{
+		let merged = Events::select_labeled( streams.into_iter().map( |events| ( (), events ) ).collect() );
+
+		Events::from_adapter( merged, |events| MergeDedup
+		{
+			seen  : HashSet::new()  ,
+			expiry: VecDeque::new() ,
+			events                  ,
+			key                     ,
+			window                  ,
+			timer                   ,
+		})
+	}
+}
+
+
+
+struct MergeDedup<Event, K, F, T> where Event: Clone + 'static + Send, T: Timer
+{
+	events: Events<( (), Event )>                ,
+	key   : F                                    ,
+	window: Duration                             ,
+	timer : T                                    ,
+	seen  : HashSet<K>                           ,
+
+	// The keys in `seen`, in the order they were seen. All windows have the same length, so
+	// the front one always expires first.
+	//
+	expiry: VecDeque<( Pin<Box< T::Sleep >>, K )> ,
+}
+
+
+
+impl<Event, K, F, T> Adapter<Event> for MergeDedup<Event, K, F, T>
+
+	where Event: Clone + 'static + Send                ,
+	      K    : Hash + Eq + Clone + Send + 'static    ,
+	      F    : FnMut( &Event ) -> K + Send + 'static ,
+	      T    : Timer                                 ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Event> >
+	{
+		loop
+		{
+			let (_, evt) = match ready!( Pin::new( &mut self.events ).poll_next( cx ) )
+			{
+				Some( item ) => item                      ,
+				None         => return Poll::Ready( None ),
+			};
+
+			self.expire();
+
+			let key = (self.key)( &evt );
+
+			if self.seen.contains( &key )
+			{
+				continue;
+			}
+
+			if let Some( sleep ) = self.start()
+			{
+				self.seen.insert( key.clone() );
+				self.expiry.push_back(( sleep, key ));
+			}
+
+			return Poll::Ready( Some( evt ) );
+		}
+	}
+
+
+	fn close( &mut self )
+	{
+		self.events.close();
+	}
+}
+
+
+
+impl<Event, K, F, T> MergeDedup<Event, K, F, T>
+
+	where Event: Clone + 'static + Send             ,
+	      K    : Hash + Eq + Clone + Send + 'static ,
+	      T    : Timer                              ,
+{
+	// Expired keys only matter when the next event arrives, so we don't ask to be woken up for them.
+	//
+	fn expire( &mut self )
+	{
+		let mut cx = Context::from_waker( futures::task::noop_waker_ref() );
+
+		while let Some(( sleep, _ )) = self.expiry.front_mut()
+		{
+			if sleep.as_mut().poll( &mut cx ).is_pending()
+			{
+				return;
+			}
+
+			let (_, key) = self.expiry.pop_front().expect( "expiry is not empty" );
+
+			self.seen.remove( &key );
+		}
+	}
+
+
+	// Start a window right away, for timers that only start when they are polled. Returns None
+	// if it has already elapsed.
+	//
+	fn start( &self ) -> Option<Pin<Box< T::Sleep >>>
+	{
+		let mut sleep = Box::pin( self.timer.sleep( self.window ) );
+		let mut cx    = Context::from_waker( futures::task::noop_waker_ref() );
+
+		match sleep.as_mut().poll( &mut cx )
+		{
+			Poll::Ready  (()) => None         ,
+			Poll::Pending     => Some( sleep ),
+		}
+	}
+}
@@ -1,4 +1,4 @@
-use crate :: { Filter, Observe, ObserveLocal };
+use crate :: { import::*, Filter, Observe, ObserveLocal, Timer, reduce::Reducer, ttl::Ttl };
 
 /// Indicate that a type is observable. You can call [`observe`](Observable::observe) to get a
 /// stream of events.
@@ -92,6 +92,42 @@ pub trait Observable<Event>
    /// to filter events with a predicate.
    //
    fn observe( &mut self, options: ObserveConfig<Event> ) -> Observe<'_, Event, Self::Error >;
+
+
+   /// Observe with a [bounded channel](Channel::Bounded) of `size` and an optional filter. Pass `None`
+   /// to receive all events.
+   ///
+   /// ```
+   /// # use pharos::*;
+   /// # async fn task( mut pharos: Pharos<usize> ) -> Result<(), PharErr> {
+   /// let small = pharos.observe_bounded( 10, Filter::Pointer( |evt| *evt < 3 ) ).await?;
+   /// let all   = pharos.observe_bounded( 10, None ).await?;
+   /// # Ok(()) }
+   /// ```
+   //
+   fn observe_bounded( &mut self, size: usize, filter: impl Into< Option<Filter<Event>> > ) -> Observe<'_, Event, Self::Error >
+
+      where Self: Sized
+   {
+      let mut options = ObserveConfig::from( Channel::Bounded( size ) );
+      options.filter  = filter.into();
+
+      self.observe( options )
+   }
+
+
+   /// Observe with an [unbounded channel](Channel::Unbounded) and an optional filter. Pass `None`
+   /// to receive all events.
+   //
+   fn observe_unbounded( &mut self, filter: impl Into< Option<Filter<Event>> > ) -> Observe<'_, Event, Self::Error >
+
+      where Self: Sized
+   {
+      let mut options = ObserveConfig::from( Channel::Unbounded );
+      options.filter  = filter.into();
+
+      self.observe( options )
+   }
 }
 
 
@@ -122,9 +158,41 @@ pub trait ObservableLocal<Event>
 
 
 
+/// Stricter bounds for events that need to be shared between threads by reference.
+///
+/// Throughout this library, the event type must be `Clone + Send + 'static`. That is all that is needed
+/// to notify observers and to receive events, because every observer gets it's own clone of the event.
+///
+/// Some patterns need more. If you want to hand out `Arc<Event>` to several threads, or share `&Event`
+/// between them, `Event` also needs to be `Sync`. Rather than piling up bounds in your own code, the
+/// features that are about sharing events require `SyncEvent`, which is automatically implemented for
+/// every type that is `Clone + Send + Sync + 'static`. The default path ([Observable], [Pharos](crate::Pharos))
+/// does not require it.
+///
+/// Features that require `SyncEvent`:
+/// - [`SharedPharos::observe_sync_events`](crate::SharedPharos::observe_sync_events)
+///
+/// ```compile_fail
+/// use pharos::*;
+/// use std::cell::Cell;
+///
+/// // Cell is Send but not Sync, so this is rejected at compile time.
+/// //
+/// async fn observe( shared: SharedPharos<Cell<u8>> )
+/// {
+///    let _ = shared.observe_sync_events( ObserveConfig::default() ).await;
+/// }
+/// ```
+//
+pub trait SyncEvent: Clone + Send + Sync + 'static {}
+
+impl<T> SyncEvent for T where T: Clone + Send + Sync + 'static {}
+
+
+
 /// Choose the type of channel that will be used for your event stream. Used in [ObserveConfig].
 //
-#[ derive( Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord )]
+#[ derive( Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default )]
 //
 pub enum Channel
 {
@@ -139,19 +207,90 @@ pub enum Channel
    /// A channel with unbounded capacity. Note that this may lead to unbounded memory consumption if producers
    /// outpace consumers.
    //
+   #[ default ]
+   //
    Unbounded,
 
+   /// A bounded channel (the usize parameter is the queue size) where the observer receives the buffered event
+   /// with the highest priority first, rather than the oldest. You must set the priority of events with
+   /// [ObserveConfig::priority], otherwise [observe](Observable::observe) will return an error.
+   ///
+   /// At most the queue size of events are buffered, including the ones the observer is choosing between, so back
+   /// pressure starts when the queue size of events have been sent and not yet delivered. At most the queue size of
+   /// events are re-ordered at any time. Events with equal priority are delivered in the order they were sent.
+   ///
+   /// **Starvation**: as long as the producer keeps sending higher priority events faster than the observer
+   /// consumes them, lower priority events will stay in the buffer and will only be delivered once the
+   /// observer catches up. Since the buffer is bounded, the producer will eventually block on back pressure
+   /// while low priority events are waiting, so nothing is lost, but low priority events can be delayed
+   /// indefinitely.
+   ///
+   /// The minimum valid buffer size is 1.
+   //
+   Priority(usize),
+
+   /// A buffer that holds a single event. When a new event is sent while the previous one has not been consumed
+   /// yet, the two are combined into one with the function you set with [ObserveConfig::reduce], eg. to sum deltas
+   /// or merge partial updates. Otherwise [observe](Observable::observe) will return an error.
+   ///
+   /// The observer never creates back pressure, and it always receives the combination of everything that was
+   /// sent since it last read an event.
+   //
+   Reduce,
+
    /// This enum might grow in the future, thanks to this that won't be a breaking change.
    //
    __NonExhaustive__
 }
 
 
-impl Default for Channel
+/// What happens when code of an observer panics while an event is being delivered to it. Set it with
+/// [ObserveConfig::panic_policy].
+///
+/// The code of an observer runs inside of the call that notifies: it's [filter](ObserveConfig::filter_boxed)
+/// and the functions passed to observe methods like [observe_expand](crate::Pharos::observe_expand). If that
+/// code panics, the panic unwinds through the notifier and the observers that come after it don't receive
+/// the event.
+///
+/// Catching panics needs `std`. So does the rest of pharos, so this is not behind a feature.
+//
+#[ derive( Debug, Copy, Clone, PartialEq, Eq, Default ) ]
+//
+pub enum PanicPolicy
 {
-   fn default() -> Self
+   /// Let the panic unwind through the call that notifies.
+   //
+   #[ default ]
+   //
+   Propagate,
+
+   /// Catch the panic and unsubscribe the observer. The other observers still receive the event. The observer is
+   /// reported with [UnsubscribeReason::Panicked](crate::UnsubscribeReason::Panicked), and the handler set with
+   /// [Pharos::on_error](crate::Pharos::on_error) receives an error of kind [ErrorKind::Panicked](crate::ErrorKind::Panicked).
+   ///
+   /// This relies on unwinding, so it doesn't help when panics abort, eg. on most wasm targets. A panic can leave
+   /// data the observer shares with other code in an inconsistent state, which is why the observer is removed.
+   //
+   Isolate,
+}
+
+
+
+/// Identifies a group of observers. Set it with [ObserveConfig::group] and notify only the observers in
+/// that group with [Pharos::notify_group](crate::Pharos::notify_group).
+///
+/// What a group means is up to you, eg. a tenant in a multi-tenant application.
+//
+#[ derive( Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash ) ]
+//
+pub struct GroupId( pub u64 );
+
+
+impl From<u64> for GroupId
+{
+   fn from( id: u64 ) -> Self
    {
-      Channel::Unbounded
+      Self( id )
    }
 }
 
@@ -198,8 +337,18 @@ impl Default for Channel
 //
 pub struct ObserveConfig<Event> where Event: Clone + 'static + Send
 {
-   pub(crate) channel: Channel,
-   pub(crate) filter : Option<Filter<Event>>,
+   pub(crate) channel      : Channel,
+   pub(crate) filter       : Option<Filter<Event>>,
+   pub(crate) group        : Option<GroupId>,
+   pub(crate) priority     : Option<fn(&Event) -> u64>,
+   pub(crate) backfill     : Option<Backfill<Event>>,
+   pub(crate) reconnectable: bool,
+   pub(crate) gate         : Option<Arc<AtomicBool>>,
+   pub(crate) reduce       : Option<Reducer<Event>>,
+   pub(crate) name         : Option<Arc<str>>,
+   pub(crate) prefill      : Vec<Event>,
+   pub(crate) panic_policy : PanicPolicy,
+   pub(crate) ttl          : Option<Ttl>,
 }
 
 
@@ -207,6 +356,14 @@ pub struct ObserveConfig<Event> where Event: Clone + 'static + Send
 /// Create a default configuration:
 /// - no filter
 /// - an unbounded channel
+/// - no group
+/// - no backfill
+/// - not reconnectable
+/// - no gate
+/// - no name
+/// - no prefill
+/// - panics propagate
+/// - no ttl
 //
 impl<Event> Default for ObserveConfig<Event> where Event: Clone + 'static + Send
 {
@@ -214,8 +371,18 @@ impl<Event> Default for ObserveConfig<Event> where Event: Clone + 'static + Send
    {
       Self
       {
-         channel: Channel::default(),
-         filter : None              ,
+         channel      : Channel::default()    ,
+         filter       : None                  ,
+         group        : None                  ,
+         priority     : None                  ,
+         backfill     : None                  ,
+         reconnectable: false                 ,
+         gate         : None                  ,
+         reduce       : None                  ,
+         name         : None                  ,
+         prefill      : Vec::new()            ,
+         panic_policy : PanicPolicy::default(),
+         ttl          : None                  ,
       }
    }
 }
@@ -256,9 +423,213 @@ impl<Event> ObserveConfig<Event> where Event: Clone + 'static + Send
       self.filter = Some( Filter::Closure( Box::new(filter) ) );
       self
    }
+
+
+   /// Put this observer in a group. Observers in a group still receive all events sent with
+   /// [SinkExt::send](https://docs.rs/futures-preview/0.3.0-alpha.19/futures/sink/trait.SinkExt.html#method.send),
+   /// but they are also the only ones to receive events sent with
+   /// [Pharos::notify_group](crate::Pharos::notify_group) for that group.
+   //
+   pub fn group( mut self, group: impl Into<GroupId> ) -> Self
+   {
+      self.group = Some( group.into() );
+      self
+   }
+
+
+   /// Set the priority of events for [Channel::Priority]. Events with a higher value are delivered first.
+   /// This is ignored for other channel types.
+   //
+   pub fn priority( mut self, key: fn(&Event) -> u64 ) -> Self
+   {
+      self.priority = Some( key );
+      self
+   }
+
+
+   /// Set the function that combines the pending event with a new one for [Channel::Reduce]. The first
+   /// argument is the pending event, the second the new one. This is ignored for other channel types.
+   ///
+   /// ```
+   /// use pharos::*;
+   ///
+   /// let opts = ObserveConfig::<usize>::default()
+   ///
+   ///    .channel( Channel::Reduce              )
+   ///    .reduce ( |pending, new| pending + new )
+   /// ;
+   /// ```
+   //
+   pub fn reduce( mut self, reduce: impl FnMut( Event, Event ) -> Event + Send + 'static ) -> Self
+   {
+      self.reduce = Some( Reducer::new( reduce ) );
+      self
+   }
+
+
+   /// Load history for a new observer, eg. from a database, before it starts receiving live events.
+   ///
+   /// The function is called when the observer subscribes and the events it returns are delivered
+   /// on the stream before any live event. Live events that are sent while the backfill is still running
+   /// are buffered in the channel and delivered after the backfill, so causal order is preserved.
+   ///
+   /// The events from the backfill don't count towards the capacity of a bounded channel. However,
+   /// live events sent during the backfill do, so with a bounded channel notifying might have to
+   /// wait until the observer starts consuming.
+   ///
+   /// [Pharos](crate::Pharos) awaits the backfill inside [observe](Observable::observe). [SharedPharos](crate::SharedPharos)
+   /// releases it's lock before awaiting it, so other tasks can keep notifying in the mean time.
+   //
+   pub fn backfill<F, Fut>( mut self, backfill: F ) -> Self
+
+      where F  : FnOnce() -> Fut + Send + 'static               ,
+            Fut: Future< Output = Vec<Event> > + Send + 'static ,
+   {
+      self.backfill = Some( Backfill( Box::new( move || backfill().boxed() ) ) );
+      self
+   }
+
+
+   /// Allow replacing the channel of this observer with [Events::reconnect](crate::Events::reconnect), eg. after
+   /// a transient failure on the receiving end. The observer keeps it's filter, group and channel type.
+   ///
+   /// A reconnectable observer stays registered as long as it's [Events](crate::Events) exist, even when
+   /// it's channel was closed.
+   //
+   pub fn reconnectable( mut self ) -> Self
+   {
+      self.reconnectable = true;
+      self
+   }
+
+
+   /// Only deliver events while `gate` is `true`. While it's `false`, events are skipped for this observer and
+   /// counted as [dropped](crate::Events::dropped). The subscription stays active, so delivery resumes
+   /// as soon as the flag is set again.
+   ///
+   /// The flag can be shared by many observers, eg. as a circuit breaker or a feature flag. Events that
+   /// are filtered out by the [filter](ObserveConfig::filter) don't count as dropped.
+   //
+   pub fn gate( mut self, gate: Arc<AtomicBool> ) -> Self
+   {
+      self.gate = Some( gate );
+      self
+   }
+
+
+   /// Give this observer a name. It's reported in [LifecycleEvent::Subscribed](crate::LifecycleEvent::Subscribed),
+   /// so you can tell observers apart in the [lifecycle events](crate::Pharos::lifecycle_events) of an observable.
+   //
+   pub fn name( mut self, name: impl Into<Arc<str>> ) -> Self
+   {
+      self.name = Some( name.into() );
+      self
+   }
+
+
+   /// Put these events in the channel when the observer subscribes, so it has something to process right away.
+   ///
+   /// The events are sent into the channel in order, like live events, but without going through the
+   /// [filter](ObserveConfig::filter) or [gate](ObserveConfig::gate). They count towards the capacity of a bounded
+   /// channel. When there are more events than fit, the first ones are kept and the rest is dropped without
+   /// counting as [dropped](crate::Events::dropped), so at most `n` events are prefilled for [Channel::Bounded]`(n)`.
+   /// A [Channel::Reduce] combines them into one.
+   ///
+   /// Unlike a [backfill](ObserveConfig::backfill), the prefill is in the channel itself, so events from the
+   /// backfill are delivered before it. It is not sent again when an observer [reconnects](crate::Events::reconnect).
+   ///
+   /// ```
+   /// use pharos::*;
+   ///
+   /// let opts = ObserveConfig::<usize>::default()
+   ///
+   ///    .channel( Channel::Bounded( 2 ) )
+   ///    .prefill( vec![ 1, 2, 3 ]       ) // 3 is dropped.
+   /// ;
+   /// ```
+   //
+   pub fn prefill( mut self, events: Vec<Event> ) -> Self
+   {
+      self.prefill = events;
+      self
+   }
+
+
+   /// Choose what happens when the code of this observer panics while an event is delivered. See [PanicPolicy].
+   ///
+   /// ```
+   /// use pharos::*;
+   ///
+   /// let opts = ObserveConfig::<usize>::default()
+   ///
+   ///    .filter_boxed( |evt| 10 / *evt > 1    )
+   ///    .panic_policy( PanicPolicy::Isolate )
+   /// ;
+   /// ```
+   //
+   pub fn panic_policy( mut self, policy: PanicPolicy ) -> Self
+   {
+      self.panic_policy = policy;
+      self
+   }
+
+
+   /// End the subscription once `ttl` has elapsed since subscribing, eg. for a live view that is only shown for
+   /// a while. `timer` is used to wait, see [Timer].
+   ///
+   /// When `ttl` has elapsed, the observer is [closed](crate::Events::close): the observable stops sending
+   /// to it, the events that were already sent are still delivered, and then the stream ends. The observable
+   /// checks the timer itself when it notifies or cleans up, eg. with [Pharos::gc](crate::Pharos::gc), so an
+   /// observer that is never polled is still removed, reported as [UnsubscribeReason::Expired](crate::UnsubscribeReason::Expired).
+   /// When the stream is [reconnected](crate::Events::reconnect), the new stream has no ttl.
+   ///
+   /// ```
+   /// use pharos::*;
+   /// use std::time::Duration;
+   ///
+   /// let opts = ObserveConfig::<usize>::default().ttl( Duration::from_secs( 30 ), async_std::task::sleep );
+   /// ```
+   //
+   pub fn ttl( mut self, ttl: Duration, timer: impl Timer ) -> Self
+   {
+      self.ttl = Some( Ttl::new( move || timer.sleep( ttl ).boxed() ) );
+      self
+   }
+
+
+   /// Take the backfill out of the config. Lets observable implementations run it themselves.
+   //
+   pub(crate) fn take_backfill( &mut self ) -> Option<Backfill<Event>>
+   {
+      self.backfill.take()
+   }
+}
+
+
+/// Loads the history for a new observer. See [ObserveConfig::backfill].
+//
+pub(crate) struct Backfill<Event>( Box< dyn FnOnce() -> BoxFuture< 'static, Vec<Event> > + Send > );
+
+
+impl<Event> Backfill<Event>
+{
+   pub(crate) async fn run( self ) -> Vec<Event>
+   {
+      (self.0)().await
+   }
+}
+
+
+impl<Event> fmt::Debug for Backfill<Event>
+{
+   fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+   {
+      write!( f, "pharos::Backfill<{}>", type_name::<Event>() )
+   }
 }
 
 
+
 /// Create a [ObserveConfig] from a [Channel], getting default values for other options.
 //
 impl<Event> From<Channel> for ObserveConfig<Event> where Event: Clone + 'static + Send
@@ -0,0 +1,64 @@
+use crate :: { import::*, Pharos, Observable, Observe, ObserveConfig, PharErr };
+
+
+/// A set of observers that can be swapped into a [Pharos] in one go with [Pharos::swap_observers].
+///
+/// You can [observe](Observable::observe) a set to subscribe new observers before they are swapped in.
+/// Dropping a set ends the streams of all it's observers once they have consumed their pending events.
+//
+pub struct ObserverSet<Event> where Event: Clone + 'static + Send
+{
+	pub(crate) pharos: Pharos<Event>,
+}
+
+
+
+impl<Event> ObserverSet<Event> where Event: Clone + 'static + Send
+{
+	/// Create an empty set with room for `capacity` observers.
+	//
+	pub fn new( capacity: usize ) -> Self
+	{
+		Self{ pharos: Pharos::new( capacity ) }
+	}
+
+
+	/// The number of observers in this set that are still listening. See [Pharos::num_observers].
+	//
+	pub fn num_observers( &mut self ) -> usize
+	{
+		self.pharos.num_observers()
+	}
+}
+
+
+
+impl<Event> Default for ObserverSet<Event> where Event: Clone + 'static + Send
+{
+	fn default() -> Self
+	{
+		Self{ pharos: Pharos::default() }
+	}
+}
+
+
+
+impl<Event> fmt::Debug for ObserverSet<Event> where Event: Clone + 'static + Send
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		write!( f, "pharos::ObserverSet<{}>", type_name::<Event>() )
+	}
+}
+
+
+
+impl<Event> Observable<Event> for ObserverSet<Event> where Event: Clone + 'static + Send
+{
+	type Error = PharErr;
+
+	fn observe( &mut self, options: ObserveConfig<Event> ) -> Observe<'_, Event, Self::Error >
+	{
+		self.pharos.observe( options )
+	}
+}
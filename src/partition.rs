@@ -0,0 +1,281 @@
+use crate :: { import::*, Events, events::Adapter };
+use std   :: { collections::HashMap, hash::Hash, task::Waker };
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Split the events into a separate stream per key. The returned [Partition] yields a new [Events] together
+	/// with it's key every time an event with a key that wasn't seen before arrives. That event, and all later
+	/// events with the same key, are delivered to this child stream. Use this to handle the variants of an enum
+	/// in different tasks.
+	///
+	/// There is no task doing the routing, events are routed whenever the partition or any of it's children is
+	/// polled. A child that is not read from doesn't slow down the others: the channels of the children are
+	/// unbounded, so their events pile up in memory until they are read. Backpressure only applies between the
+	/// observable and this observer as a whole.
+	///
+	/// - When a child is dropped, further events for it's key are discarded. No new child is created for
+	///   that key. The same goes for a child after you call [close](Events::close) on it.
+	/// - When the partition is dropped, events for keys that have no child yet are discarded. Existing
+	///   children keep receiving their events.
+	/// - When the observable ends the stream, all children end after delivering their pending events.
+	///
+	/// The task that polls while no events are available is woken when the next one arrives. If it then stops
+	/// polling without dropping the child or partition it polled, routing stops until another one is polled.
+	/// So drop the children and the partition once you no longer need them.
+	///
+	/// ```
+	/// use pharos::*;
+	/// use futures::StreamExt;
+	///
+	/// # async fn task( events: Events<Result<usize, String>> ) {
+	/// let mut partition = events.partition_by( |evt| evt.is_ok() );
+	///
+	/// while let Some(( is_ok, child )) = partition.next().await
+	/// {
+	///    // spawn a task that handles `child`...
+	/// }
+	/// # }
+	/// ```
+	//
+	pub fn partition_by<K, F>( self, key: F ) -> Partition<K, Event>
+
+		where K: Hash + Eq + Clone + Send + 'static ,
+		      F: FnMut( &Event ) -> K + Send + 'static ,
+	{
+		let router = Router
+		{
+			events   : self            ,
+			key      : Box::new( key ) ,
+			children : HashMap::new()  ,
+			new      : VecDeque::new() ,
+			announcer: None            ,
+			waiting  : Vec::new()      ,
+			partition: true            ,
+			done     : false           ,
+		};
+
+		Partition{ router: Arc::new( SyncMutex::new( router ) ) }
+	}
+}
+
+
+
+/// A stream of child streams, one per key. Created by [Events::partition_by].
+//
+pub struct Partition<K, Event> where Event: Clone + 'static + Send
+{
+	router: Arc<SyncMutex< Router<K, Event> >>,
+}
+
+
+
+struct Router<K, Event> where Event: Clone + 'static + Send
+{
+	events   : Events<Event>                                  ,
+	key      : Box< dyn FnMut( &Event ) -> K + Send >         ,
+	children : HashMap< K, FutUnboundedSender<Event> >        ,
+
+	// Children that have not been handed out by the partition yet.
+	//
+	new      : VecDeque<( K, Events<Event> )>                 ,
+	announcer: Option<Waker>                                  ,
+
+	// Everyone that is waiting for the source. Only the last one is registered with the source,
+	// so if that one goes away, the others have to be woken up.
+	//
+	waiting  : Vec<Waker>                                     ,
+	partition: bool                                           ,
+	done     : bool                                           ,
+}
+
+
+impl<K, Event> Router<K, Event>
+
+	where K    : Hash + Eq + Clone + Send + 'static ,
+	      Event: Clone + 'static + Send             ,
+{
+	// Route everything the source has for us.
+	//
+	fn drive( &mut self, cx: &mut Context<'_>, this: &Arc<SyncMutex< Router<K, Event> >> )
+	{
+		while !self.done
+		{
+			let evt = match Pin::new( &mut self.events ).poll_next( cx )
+			{
+				Poll::Ready( Some(evt) ) => evt,
+
+				Poll::Ready( None ) =>
+				{
+					self.done = true;
+
+					// The children end once they have delivered their pending events.
+					//
+					self.children.clear();
+					self.wake_waiting();
+
+					if let Some( w ) = self.announcer.take() { w.wake(); }
+
+					return;
+				}
+
+				Poll::Pending =>
+				{
+					if !self.waiting.iter().any( |w| w.will_wake( cx.waker() ) )
+					{
+						self.waiting.push( cx.waker().clone() );
+					}
+
+					return;
+				}
+			};
+
+
+			let key = (self.key)( &evt );
+
+			if let Some( tx ) = self.children.get( &key )
+			{
+				// Fails when the child is closed or dropped.
+				//
+				let _ = tx.unbounded_send( evt );
+			}
+
+			else if self.partition
+			{
+				let (tx, rx) = mpsc::unbounded();
+
+				let _ = tx.unbounded_send( evt );
+				let child = self.events.sibling( Child{ rx, router: this.clone() } );
+
+				self.children.insert( key.clone(), tx );
+				self.new.push_back(( key, child ));
+
+				if let Some( w ) = self.announcer.take() { w.wake(); }
+			}
+		}
+	}
+}
+
+
+
+impl<K, Event> Router<K, Event> where Event: Clone + 'static + Send
+{
+	fn wake_waiting( &mut self )
+	{
+		for w in self.waiting.drain(..) { w.wake(); }
+	}
+}
+
+
+
+impl<K, Event> Stream for Partition<K, Event>
+
+	where K    : Hash + Eq + Clone + Send + 'static ,
+	      Event: Clone + 'static + Send             ,
+{
+	type Item = (K, Events<Event>);
+
+	fn poll_next( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll< Option<Self::Item> >
+	{
+		let mut router = self.router.lock().expect( "lock router" );
+
+		if router.new.is_empty()
+		{
+			router.drive( cx, &self.router );
+		}
+
+		match router.new.pop_front()
+		{
+			Some( item ) => Poll::Ready( Some( item ) ),
+
+			None if router.done => Poll::Ready( None ),
+
+			None =>
+			{
+				router.announcer = Some( cx.waker().clone() );
+				Poll::Pending
+			}
+		}
+	}
+}
+
+
+
+impl<K, Event> Drop for Partition<K, Event> where Event: Clone + 'static + Send
+{
+	fn drop( &mut self )
+	{
+		// The children that were not handed out hold on to the router, so drop them. They
+		// lock the router when dropped, so only after we release the lock.
+		//
+		let _new = match self.router.lock()
+		{
+			Ok( mut router ) =>
+			{
+				router.partition = false;
+				router.wake_waiting();
+
+				std::mem::take( &mut router.new )
+			}
+
+			Err(_) => return,
+		};
+	}
+}
+
+
+
+impl<K, Event> fmt::Debug for Partition<K, Event> where Event: Clone + 'static + Send
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		write!( f, "pharos::Partition<{}, {}>", type_name::<K>(), type_name::<Event>() )
+	}
+}
+
+
+
+struct Child<K, Event> where Event: Clone + 'static + Send
+{
+	rx    : FutUnboundedReceiver<Event>        ,
+	router: Arc<SyncMutex< Router<K, Event> >> ,
+}
+
+
+
+impl<K, Event> Adapter<Event> for Child<K, Event>
+
+	where K    : Hash + Eq + Clone + Send + 'static ,
+	      Event: Clone + 'static + Send             ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Event> >
+	{
+		if let Poll::Ready( evt ) = Pin::new( &mut self.rx ).poll_next( cx )
+		{
+			return Poll::Ready( evt );
+		}
+
+		self.router.lock().expect( "lock router" ).drive( cx, &self.router );
+
+		Pin::new( &mut self.rx ).poll_next( cx )
+	}
+
+
+	fn close( &mut self )
+	{
+		self.rx.close();
+	}
+}
+
+
+
+impl<K, Event> Drop for Child<K, Event> where Event: Clone + 'static + Send
+{
+	fn drop( &mut self )
+	{
+		if let Ok( mut router ) = self.router.lock()
+		{
+			router.wake_waiting();
+		}
+	}
+}
@@ -1,4 +1,5 @@
-use crate :: { import::*, Observable, Observe, Events, ObserveConfig, events::Sender, PharErr, ErrorKind, Channel };
+use crate :: { import::*, Observable, Observe, Events, ObserveConfig, events::Sender, PharErr, ErrorKind, Channel, GroupId, ObserverSet, LifecycleEvent, UnsubscribeReason, lifecycle::Lifecycle, ObserverId, CapacityReport };
+use std   :: { collections::HashMap };
 
 
 /// The Pharos lighthouse. When you implement [Observable] on your type, you can forward
@@ -16,9 +17,35 @@ use crate :: { import::*, Observable, Observe, Events, ObserveConfig, events::Se
 /// returned an error, which means it is closed or disconnected. However, we currently don't
 /// compact the vector. Slots are reused for new observers, but the vector never shrinks.
 ///
-/// **Note**: we only detect that observers can be removed when [SinkExt::send](https://docs.rs/futures-preview/0.3.0-alpha.19/futures/sink/trait.SinkExt.html#method.send) or [Pharos::num_observers]
-/// is being called. Otherwise, we won't find out about disconnected observers and the vector of observers
-/// will not mark deleted observers and thus their slots can not be reused.
+/// **Note**: we only detect that observers can be removed when [SinkExt::send](https://docs.rs/futures-preview/0.3.0-alpha.19/futures/sink/trait.SinkExt.html#method.send),
+/// [Pharos::num_observers], [Pharos::gc] or [observe](Observable::observe) is being called. Otherwise, we won't find out
+/// about disconnected observers and the vector of observers will not mark deleted observers and thus their slots can
+/// not be reused. If your observable rarely notifies but observers come and go, call [Pharos::gc] now and then.
+///
+/// Every observer that receives an event gets a clone of it, except for the last one, which gets the original.
+/// So with a single observer, events are never cloned. If your events are big and have many observers, consider
+/// wrapping them in an `Arc`.
+///
+/// ## Order of evaluation
+///
+/// For every notification, each observer goes through the same steps, in this order. An observer that doesn't
+/// pass a step doesn't see the later ones:
+///
+/// 1. selection by the notification, eg. the group for [notify_group](Pharos::notify_group). Plain notifications
+///    select every observer.
+/// 2. the [filter](crate::ObserveConfig::filter) of the observer. It gets a reference, so filtering never clones.
+/// 3. the [gate](crate::ObserveConfig::gate) of the observer. Events that passed the filter but not the gate count
+///    as [dropped](crate::Events::dropped).
+/// 4. the event is cloned for this observer, unless it's the last one to receive it.
+/// 5. the transformation of the observer, if any, eg. the one of [observe_with_output](Pharos::observe_with_output),
+///    which gets the owned event.
+/// 6. the event is put in the channel.
+///
+/// The pharos has no filter of it's own. When the [buffer limit](Pharos::set_buffer_limit) drops the new event, steps 1 to 3
+/// still decide which observers count it as dropped.
+///
+/// Steps 2 and 5 run code of the observer. With [PanicPolicy::Isolate](crate::PanicPolicy::Isolate), a panic in
+/// them unsubscribes that observer and the others still go through all steps.
 ///
 /// The [Sink](https://docs.rs/futures-preview/0.3.0-alpha.19/futures/sink/trait.Sink.html) impl
 /// is not very optimized for the moment. It just loops over all observers in each poll method
@@ -32,12 +59,69 @@ pub struct Pharos<Event>  where Event: 'static + Clone + Send
 	// Observers never get moved. Their index stays stable, so that when we free a slot,
 	// we can store that in `free_slots`.
 	//
-	observers : Vec<Option< Sender<Event> >>,
-	free_slots: Vec<usize>                  ,
-	closed    : bool                        ,
+	observers : Vec<Option< Sender<Event> >>      ,
+	free_slots: Vec<usize>                        ,
+	closed    : bool                              ,
+
+	// The index the fan out starts at when fair is set. It moves on with every notification.
+	//
+	fair      : bool                              ,
+	start     : usize                             ,
+	lifecycle : Lifecycle                         ,
+
+	// See set_buffer_limit.
+	//
+	limit     : Option<( usize, BufferPolicy )>   ,
+
+	// See on_error.
+	//
+	on_error  : Option< ErrorHandler >            ,
+
+	// The counters of the observers when capacity profiling started.
+	//
+	baseline  : HashMap< ObserverId, (u64, u64) > ,
 }
 
 
+type ErrorHandler = Box< dyn FnMut( ObserverId, PharErr ) + Send >;
+
+
+
+
+/// What a [Pharos] does with a notification while the observers have more events buffered than the limit
+/// set with [Pharos::set_buffer_limit].
+//
+#[ derive( Debug, Copy, Clone, PartialEq, Eq ) ]
+//
+pub enum BufferPolicy
+{
+	/// Wait until observers have consumed enough events. This creates back pressure, like a
+	/// [bounded channel](crate::Channel::Bounded) that is full.
+	//
+	Wait,
+
+	/// Discard the new event. It's counted as [dropped](crate::Events::dropped) for every observer that
+	/// would have received it.
+	//
+	DropNewest,
+
+	/// Make room by discarding the oldest events of the observer with the most events buffered, until the
+	/// total is under the limit, then send the new event. The discarded events are counted as
+	/// [dropped](crate::Events::dropped) for that observer.
+	///
+	/// The events stop counting right away, but they stay in memory until the observer polls it's
+	/// [Events](crate::Events), which skips them. For a [priority channel](crate::Channel::Priority) those are
+	/// the events it would yield next. For [observe_lossy](Pharos::observe_lossy) the [Lossy::Lag](crate::Lossy::Lag)
+	/// comes before the next event that is sent, not where the events were discarded.
+	//
+	DropOldest,
+
+	/// Return [ErrorKind::BufferFull](crate::ErrorKind::BufferFull) from notifying. The pharos is not closed,
+	/// so you can notify again later.
+	//
+	Error,
+}
+
 
 
 impl<Event> fmt::Debug for Pharos<Event>  where Event: 'static + Clone + Send
@@ -66,10 +150,32 @@ impl<Event> Pharos<Event>  where Event: 'static + Clone + Send
 			observers : Vec::with_capacity( capacity ),
 			free_slots: Vec::with_capacity( capacity ),
 			closed    : false                         ,
+			fair      : false                         ,
+			start     : 0                             ,
+			lifecycle : Lifecycle::default()          ,
+			limit     : None                          ,
+			on_error  : None                          ,
+			baseline  : HashMap::new()                ,
 		}
 	}
 
 
+	/// Rotate the order in which observers are served on every notification.
+	///
+	/// Observers are served one after the other. On a bounded channel, an observer that is persistently slow delays
+	/// every notification for all observers that come after it. With `fair` set, the observer that is served first
+	/// moves on by one for each notification, so a slow observer doesn't always penalize the same observers.
+	///
+	/// This means the order in which observers receive an event changes from one notification to the next.
+	/// The order of events for a given observer is not affected. By default this is off and observers are served
+	/// in the order they subscribed (reusing free slots).
+	//
+	pub fn set_fair( &mut self, fair: bool )
+	{
+		self.fair = fair;
+	}
+
+
 	/// Returns the size of the vector used to store the observers. Useful for debugging and testing if it
 	/// seems to get to big.
 	//
@@ -99,14 +205,343 @@ impl<Event> Pharos<Event>  where Event: 'static + Clone + Send
 
 				else
 				{
-					self.free_slots.push( i );
-					*opt = None
+					Self::prune( &mut self.free_slots, &self.lifecycle, i, opt );
 				}
 			}
 		}
 
 		count
 	}
+
+
+	/// Remove the observers that have closed or dropped their [Events], so their slots can be reused, and return how many
+	/// were removed. Notifying does this as well, so you only need it for an observable that rarely notifies: without
+	/// notifications, observers that went away keep their channel and their events in memory until `gc` is called.
+	/// Observing also calls it, so slots of observers that went away are reused before the storage grows.
+	///
+	/// [Reconnectable](crate::ObserveConfig::reconnectable) observers are kept as long as their [Events] exist.
+	//
+	pub fn gc( &mut self ) -> usize
+	{
+		let free = self.free_slots.len();
+
+		for (i, opt) in self.observers.iter_mut().enumerate()
+		{
+			if opt.as_mut().is_some_and( |obs| obs.is_closed() )
+			{
+				Self::prune( &mut self.free_slots, &self.lifecycle, i, opt );
+			}
+		}
+
+		self.free_slots.len() - free
+	}
+
+
+	/// Replace all observers by the ones in `new` and return the current ones. Notifications reach either the old
+	/// or the new set, never a mix, so you can re-subscribe everyone after a change of configuration.
+	///
+	/// The returned set still holds the old observers. Drop it to end their streams, or swap it back in later.
+	/// If the pharos is closed, the observers of `new` are dropped instead.
+	//
+	pub fn swap_observers( &mut self, mut new: ObserverSet<Event> ) -> ObserverSet<Event>
+	{
+		self.unsubscribe_all( UnsubscribeReason::Swapped );
+
+		for obs in new.pharos.observers.iter().flatten()
+		{
+			self.lifecycle.notify( LifecycleEvent::Subscribed{ id: obs.id(), name: obs.name() } );
+		}
+
+		std::mem::swap( &mut self.observers , &mut new.pharos.observers  );
+		std::mem::swap( &mut self.free_slots, &mut new.pharos.free_slots );
+
+		if self.closed
+		{
+			self.observers .clear();
+			self.free_slots.clear();
+		}
+
+		new
+	}
+
+
+	/// Limit the number of events that are buffered across all observers. Channels that are bounded per observer
+	/// still let the total grow with the number of observers, this puts a cap on memory use for the whole pharos.
+	/// `policy` decides what happens to a notification while the total is at or over `limit`. Pass `None` to
+	/// remove the limit.
+	///
+	/// An event is counted when it's put in the channel of an observer, and until that observer consumes it. Sending
+	/// an event to several observers counts it several times, so the limit can be overshot by the observers of a single
+	/// notification. Events that [adapters](crate::Events::delay) have taken out of the channel no longer count.
+	///
+	/// Observers that have closed or dropped their [Events](crate::Events) don't count, even if they never consumed their events.
+	//
+	pub fn set_buffer_limit( &mut self, limit: Option<usize>, policy: BufferPolicy )
+	{
+		self.limit = limit.map( |limit| (limit, policy) );
+	}
+
+
+	/// Call `handler` whenever sending to an observer fails for another reason than the observer having gone away,
+	/// instead of handling it at every place you notify. It gets the id of the observer and the error. The observer
+	/// is removed, as it would be without a handler. Observers that close or drop their [Events] are just removed,
+	/// without calling `handler`. Setting a new handler replaces the previous one.
+	//
+	pub fn on_error( &mut self, handler: impl FnMut( ObserverId, PharErr ) + Send + 'static )
+	{
+		self.on_error = Some( Box::new( handler ) );
+	}
+
+
+	/// The number of events that are buffered across all observers. See [set_buffer_limit](Pharos::set_buffer_limit).
+	//
+	pub fn buffered( &self ) -> usize
+	{
+		self.observers.iter().flatten()
+
+			.filter( |obs| obs.is_live() )
+			.map   ( |obs| obs.buffered() )
+			.sum()
+	}
+
+
+	/// The number of events that are buffered but not yet consumed, for every observer. Use [Events::id] to find
+	/// out which entry belongs to which observer, eg. to decide whether to wait for an observer during shutdown.
+	///
+	/// Observers that closed or dropped their [Events] are reported until the pharos notices, see
+	/// [lifecycle_events](Pharos::lifecycle_events). Like for [buffered](Pharos::buffered), events that
+	/// [adapters](Events::delay) have taken out of the channel no longer count.
+	//
+	pub fn pending_len_per_observer( &self ) -> Vec<( ObserverId, usize )>
+	{
+		self.observers.iter().flatten().map( |obs| ( obs.id(), obs.buffered() ) ).collect()
+	}
+
+
+	/// Unsubscribe the observer with this id from the side of the observable, eg. to get rid of a consumer that
+	/// misbehaves. Get the id from [Events::id], [lifecycle_events](Pharos::lifecycle_events) or
+	/// [pending_len_per_observer](Pharos::pending_len_per_observer).
+	///
+	/// The channel of the observer is closed, so it's stream ends after delivering the events it has buffered.
+	/// It can no longer [reconnect](Events::reconnect). It's reported with [UnsubscribeReason::Removed] in the
+	/// lifecycle events. Returns whether an observer with this id was registered.
+	//
+	pub fn unsubscribe( &mut self, id: ObserverId ) -> bool
+	{
+		let i = match self.observers.iter().position( |opt| opt.as_ref().is_some_and( |obs| obs.id() == id ) )
+		{
+			Some( i ) => i,
+			None      => return false,
+		};
+
+		let mut obs = self.observers[i].take().expect( "observer at position" );
+		let mut cx  = Context::from_waker( futures::task::noop_waker_ref() );
+
+		// The channels don't need to wait to close. A relay might get no chance to send what
+		// it held back, like when the pharos is closed.
+		//
+		let _ = Pin::new( &mut obs ).poll_close( &mut cx );
+
+		self.free_slots.push( i );
+		self.lifecycle.notify( LifecycleEvent::Unsubscribed{ id, reason: UnsubscribeReason::Removed } );
+
+		true
+	}
+
+
+	/// Start measuring how the buffers of the observers are used, see [capacity_report](Pharos::capacity_report).
+	/// Until you call this, reports cover the time since each observer subscribed. Calling it again starts over.
+	//
+	pub fn start_capacity_profiling( &mut self )
+	{
+		self.baseline.clear();
+
+		for obs in self.observers.iter().flatten()
+		{
+			let capacity = obs.capacity();
+
+			obs.reset_high_water();
+			self.baseline.insert( capacity.id, ( capacity.sent, capacity.consumed ) );
+		}
+	}
+
+
+	/// Report for every observer the highest number of events it had buffered, and how many events it was sent and
+	/// consumed since [start_capacity_profiling](Pharos::start_capacity_profiling), or since it subscribed if that's
+	/// later. Profiling keeps running, so you can take several reports over the same window.
+	///
+	/// Like for [buffered](Pharos::buffered), events that [adapters](Events::delay) have taken out of the channel count
+	/// as consumed.
+	///
+	/// ```
+	/// use pharos::*;
+	/// use std::time::{ Duration, Instant };
+	///
+	/// # fn task( pharos: &mut Pharos<usize> ) {
+	/// pharos.start_capacity_profiling();
+	/// let start = Instant::now();
+	///
+	/// // ... run your workload
+	///
+	/// for obs in pharos.capacity_report().observers
+	/// {
+	///    println!( "{}: needs {} slots, {:.0} events/s", obs.id, obs.high_water, obs.send_rate( start.elapsed() ) );
+	/// }
+	/// # }
+	/// ```
+	//
+	pub fn capacity_report( &self ) -> CapacityReport
+	{
+		let observers = self.observers.iter().flatten().map( |obs|
+		{
+			let mut capacity     = obs.capacity();
+			let (sent, consumed) = self.baseline.get( &capacity.id ).copied().unwrap_or_default();
+
+			capacity.sent     -= sent;
+			capacity.consumed -= consumed;
+
+			capacity
+
+		}).collect();
+
+		CapacityReport{ observers }
+	}
+
+
+	// The policy to apply if we are over the limit.
+	//
+	pub(crate) fn over_limit( &self ) -> Option<BufferPolicy>
+	{
+		let (limit, policy) = self.limit?;
+
+		( self.buffered() >= limit ).then_some( policy )
+	}
+
+
+	// Discard the oldest events of the observers with the most events buffered until we are under the
+	// limit. Returns early if nothing is left to discard.
+	//
+	fn shed_oldest( &self )
+	{
+		let limit = match self.limit { Some(( limit, _ )) => limit, None => return };
+
+		while self.buffered() >= limit
+		{
+			let slowest = self.observers.iter().flatten()
+
+				.filter    ( |obs| obs.is_live()  )
+				.max_by_key( |obs| obs.buffered() )
+			;
+
+			if !slowest.is_some_and( |obs| obs.shed() ) { return; }
+		}
+	}
+
+
+	// Wait for the observers to consume events. We need to check again after registering, in case
+	// events were consumed in the mean time.
+	//
+	pub(crate) fn poll_limit( &self, cx: &mut Context<'_> ) -> Poll<()>
+	{
+		if self.over_limit() != Some( BufferPolicy::Wait )
+		{
+			return Poll::Ready(());
+		}
+
+		for obs in self.observers.iter().flatten()
+		{
+			obs.register_waker( cx.waker() );
+		}
+
+		match self.over_limit()
+		{
+			Some( BufferPolicy::Wait ) => Poll::Pending     ,
+			_                          => Poll::Ready( () ) ,
+		}
+	}
+
+
+	/// Observe, receiving the events in `history` before any live event. Use this to restore state from events
+	/// you already have, eg. a snapshot, and then follow along.
+	///
+	/// Unlike a [backfill](crate::ObserveConfig::backfill), you supply the events directly. If `options` has a backfill
+	/// as well, `history` comes before it. Like for a backfill, `history` doesn't count towards the capacity of a bounded
+	/// channel, it's already in memory.
+	//
+	pub async fn observe_prefixed( &mut self, options: ObserveConfig<Event>, history: Vec<Event> ) -> Result< Events<Event>, PharErr >
+	{
+		let mut events = self.observe( options ).await?;
+
+		// Live events sent from now on are in the channel, behind the history.
+		//
+		events.prepend( history );
+
+		Ok( events )
+	}
+
+
+	/// Observe the subscriptions of this pharos. You receive a [LifecycleEvent::Subscribed] every time an observer
+	/// is added and a [LifecycleEvent::Unsubscribed] when it leaves, eg. to find subscriptions that leak.
+	///
+	/// Observers that close or drop their [Events] are only noticed the next time the pharos sends them an event,
+	/// counts them with [num_observers](Pharos::num_observers) or runs [gc](Pharos::gc), which observing does as well.
+	/// So that's when they are reported. When the
+	/// pharos is closed, all observers are reported as unsubscribed and the stream ends. The channel is unbounded
+	/// and the observers of the lifecycle don't show up in it.
+	///
+	/// ```
+	/// use pharos::*;
+	/// use futures::StreamExt;
+	///
+	/// # async fn task( mut pharos: Pharos<usize> ) -> Result<(), PharErr> {
+	/// let mut lifecycle = pharos.lifecycle_events();
+	///
+	/// let events = pharos.observe( ObserveConfig::default().name( "logger" ) ).await?;
+	///
+	/// if let Some( LifecycleEvent::Subscribed{ id, name } ) = lifecycle.next().await
+	/// {
+	///    assert_eq!( id, events.id() );
+	///    assert_eq!( Some( "logger".into() ), name );
+	/// }
+	/// # Ok(()) }
+	/// ```
+	//
+	pub fn lifecycle_events( &self ) -> Events<LifecycleEvent>
+	{
+		self.lifecycle.observe()
+	}
+
+
+	// Report every registered observer as unsubscribed.
+	//
+	fn unsubscribe_all( &mut self, reason: UnsubscribeReason )
+	{
+		for obs in self.observers.iter().flatten()
+		{
+			self.lifecycle.notify( LifecycleEvent::Unsubscribed{ id: obs.id(), reason } );
+		}
+	}
+
+
+	/// Whether any observer is still listening. This stops at the first one that is and does not remove
+	/// observers that have gone away, so it's cheaper than [Pharos::num_observers]. Use it to avoid creating
+	/// events nobody will receive:
+	///
+	/// ```
+	/// # use pharos::*;
+	/// # use futures::SinkExt;
+	/// # async fn task( mut pharos: Pharos<String> ) -> Result<(), PharErr> {
+	/// if pharos.is_observed()
+	/// {
+	///    pharos.send( "expensive".repeat( 1000 ) ).await?;
+	/// }
+	/// # Ok(()) }
+	/// ```
+	//
+	pub fn is_observed( &self ) -> bool
+	{
+		self.observers.iter().flatten().any( |obs| obs.is_live() )
+	}
 }
 
 
@@ -132,84 +567,288 @@ impl<Event> Observable<Event> for Pharos<Event>  where Event: 'static + Clone +
 	/// TODO: provide API for the client to compact the pharos object after reducing the
 	///       number of observers.
 	//
-	fn observe( &mut self, options: ObserveConfig<Event> ) -> Observe<'_, Event, Self::Error >
+	fn observe( &mut self, mut options: ObserveConfig<Event> ) -> Observe<'_, Event, Self::Error >
 	{
 		async move
 		{
-			if self.closed
+			self.check( &options )?;
+
+			if matches!( options.channel, Channel::Priority(_) ) && options.priority.is_none()
 			{
-				return Err( ErrorKind::Closed.into() );
+				return Err( ErrorKind::MissingPriority.into() );
 			}
 
-
-			if let Channel::Bounded(queue_size) = options.channel
+			if options.channel == Channel::Reduce && options.reduce.is_none()
 			{
-				if queue_size < 1
-				{
-					return Err( ErrorKind::MinChannelSizeOne.into() );
-				}
+				return Err( ErrorKind::MissingReducer.into() );
 			}
 
 
-			let (events, sender) = Events::new( options );
+			let backfill             = options.take_backfill();
+			let (mut events, sender) = Events::new( options );
+
+			self.register( sender );
 
 
-			// Try to reuse a free slot
+			// The sender is registered, so live events from here on will be buffered in the
+			// channel, behind the backfill.
 			//
-			if let Some( i ) = self.free_slots.pop()
+			if let Some( backfill ) = backfill
 			{
-				self.observers[i] = Some( sender );
+				events.prepend( backfill.run().await );
 			}
 
-			else
+			Ok( events )
+
+		}.boxed()
+	}
+}
+
+
+
+impl<Event> Pharos<Event> where Event: Clone + 'static + Send
+{
+	// Verify we can create an observer with these options.
+	//
+	pub(crate) fn check<T>( &self, options: &ObserveConfig<T> ) -> Result<(), PharErr>
+
+		where T: Clone + 'static + Send
+	{
+		if self.closed
+		{
+			return Err( ErrorKind::Closed.into() );
+		}
+
+
+		if let Channel::Bounded(queue_size) | Channel::Priority(queue_size) = options.channel
+		{
+			if queue_size < 1
 			{
-				self.observers.push( Some( sender ) );
+				return Err( ErrorKind::MinChannelSizeOne.into() );
 			}
+		}
 
-			Ok( events )
+		Ok(())
+	}
 
-		}.boxed()
+
+	// Store a new observer, reusing a free slot if possible.
+	//
+	pub(crate) fn register( &mut self, sender: Sender<Event> )
+	{
+		self.gc();
+
+		self.lifecycle.notify( LifecycleEvent::Subscribed{ id: sender.id(), name: sender.name() } );
+
+		if let Some( i ) = self.free_slots.pop()
+		{
+			self.observers[i] = Some( sender );
+		}
+
+		else
+		{
+			self.observers.push( Some( sender ) );
+		}
 	}
 }
 
 
 
-// See the documentation on Channel for how poll functions work for the channels we use.
+// The fan out to observers. The Sink impl and the notify methods all go through here. The `select`
+// closure decides which observers take part in a given notification.
 //
-impl<Event> Sink<Event> for Pharos<Event> where Event: Clone + 'static + Send
+impl<Event> Pharos<Event> where Event: Clone + 'static + Send
 {
-	type Error = PharErr;
+	/// Notify all observers, like [SinkExt::send](https://docs.rs/futures-preview/0.3.0-alpha.19/futures/sink/trait.SinkExt.html#method.send),
+	/// returning the number of observers that accepted the event. Observers that filter it out, that are
+	/// stopped by their [gate](crate::ObserveConfig::gate) or that turn out to be disconnected don't count.
+	///
+	/// The reach is a snapshot of the moment the event was sent. Observers that subscribe or go away later are not
+	/// reflected, and it doesn't say whether the observers will read the event. If you don't need it, ignore it
+	/// with `let _ =` or `?`.
+	///
+	/// This takes the event by value, like [SinkExt::send](https://docs.rs/futures-preview/0.3.0-alpha.19/futures/sink/trait.SinkExt.html#method.send),
+	/// so the last observer gets the original and a single observer never causes a clone. [notify_group](Pharos::notify_group)
+	/// and [notify_if](Pharos::notify_if) take a reference instead, so you keep the event to notify several groups
+	/// with it, or when the condition is false and nothing is sent. They clone it only when they send it.
+	//
+	pub async fn notify( &mut self, evt: Event ) -> Result<usize, PharErr>
+	{
+		let mut select = |_: &Sender<Event>| true;
 
+		poll_fn( |cx| self.poll_ready_where( cx, &mut select ) ).await?;
+		let reach = self.start_send_where( evt, &mut select )?;
+		poll_fn( |cx| self.poll_flush_where( cx, &mut select ) ).await?;
 
-	fn poll_ready( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
+		Ok( reach )
+	}
+
+
+	/// Notify only the observers of a given [group](crate::ObserveConfig::group). Observers that are not
+	/// in this group (including those that aren't in any group) will not receive this event. Filters still apply.
+	///
+	/// Like [SinkExt::send](https://docs.rs/futures-preview/0.3.0-alpha.19/futures/sink/trait.SinkExt.html#method.send),
+	/// this will wait for all observers in the group to be ready to accept the event. Returns the number of observers
+	/// that accepted it, like [notify](Pharos::notify).
+	//
+	pub async fn notify_group( &mut self, group: GroupId, evt: &Event ) -> Result<usize, PharErr>
+	{
+		let mut select = |obs: &Sender<Event>| obs.group() == Some( group );
+
+		poll_fn( |cx| self.poll_ready_where( cx, &mut select ) ).await?;
+		let reach = self.start_send_where( evt.clone(), &mut select )?;
+		poll_fn( |cx| self.poll_flush_where( cx, &mut select ) ).await?;
+
+		Ok( reach )
+	}
+
+
+	/// Notify all observers, but only if `cond` returns true. `cond` is only called when someone
+	/// [is listening](Pharos::is_observed), so you don't pay for an expensive condition while nobody is.
+	///
+	/// Returns the number of observers that accepted the event, like [notify](Pharos::notify), which is 0 when
+	/// nobody is listening or `cond` returns false. Returns [ErrorKind::Closed] if the pharos is closed, without
+	/// calling `cond`.
+	//
+	pub async fn notify_if( &mut self, evt: &Event, cond: impl FnOnce() -> bool ) -> Result<usize, PharErr>
+	{
+		if self.closed
+		{
+			return Err( ErrorKind::Closed.into() );
+		}
+
+		if !self.is_observed() || !cond()
+		{
+			return Ok( 0 );
+		}
+
+		self.notify( evt.clone() ).await
+	}
+
+
+	/// Flush all observers, resolving once every buffered event has been accepted by the channels.
+	///
+	/// This gives you a barrier you can await after a burst of notifications. Observers that turn out
+	/// to be disconnected are dropped.
+	///
+	/// **Note**: the channels we currently use always accept an event into their buffer as soon as it's
+	/// sent, so for them this is mostly a no-op that verifies they are still connected. Other channel
+	/// back-ends might actually have to do work here.
+	//
+	pub fn flush_all( &mut self ) -> impl Future< Output = Result<(), PharErr> > + '_
+	{
+		poll_fn( move |cx| self.poll_flush_where( cx, &mut |_| true ) )
+	}
+
+
+	// The order in which to serve observers for the current notification.
+	//
+	fn order( &self ) -> impl Iterator<Item=usize>
+	{
+		let len   = self.observers.len();
+		let start = if self.fair && len > 0 { self.start % len } else { 0 };
+
+		( 0..len ).map( move |i| ( start + i ) % len )
+	}
+
+
+	// Send to the observer at index `i`, removing it if sending fails. Returns whether it
+	// accepted the event.
+	//
+	fn send_to( &mut self, i: usize, evt: Event ) -> bool
+	{
+		let opt = &mut self.observers[i];
+
+		if let Some( obs ) = opt
+		{
+			match obs.deliver( evt )
+			{
+				Ok ( reached ) => return reached,
+				Err( e       ) => Self::report( &mut self.on_error, obs.id(), e ),
+			}
+
+			Self::prune( &mut self.free_slots, &self.lifecycle, i, opt );
+		}
+
+		false
+	}
+
+
+	// Pass an error of an observer to the handler set with on_error, unless the observer just went away.
+	//
+	fn report( on_error: &mut Option<ErrorHandler>, id: ObserverId, err: PharErr )
 	{
+		if let Some( handler ) = on_error
+		{
+			if !err.is_disconnect() { handler( id, err ); }
+		}
+	}
+
 
+	// Remove a disconnected or expired observer, unless it might still reconnect. An observer that
+	// panicked is always removed.
+	//
+	fn prune( free_slots: &mut Vec<usize>, lifecycle: &Lifecycle, i: usize, opt: &mut Option<Sender<Event>> )
+	{
+		if opt.as_ref().is_some_and( |obs| obs.is_reconnectable() && !obs.panicked() )
+		{
+			return;
+		}
+
+		if let Some( obs ) = opt.take()
+		{
+			let reason =
+
+				if      obs.panicked() { UnsubscribeReason::Panicked     }
+				else if obs.expired () { UnsubscribeReason::Expired      }
+				else                   { UnsubscribeReason::Disconnected }
+			;
+
+			#[ cfg( feature = "log" ) ]
+			//
+			if obs.panicked() { log::error!( "pharos::Pharos<{}>: observer {:?} panicked, unsubscribing it", type_name::<Event>(), obs.id() ); }
+
+			lifecycle.notify( LifecycleEvent::Unsubscribed{ id: obs.id(), reason } );
+		}
+
+		free_slots.push( i );
+	}
+
+
+	fn poll_ready_where( &mut self, cx: &mut Context<'_>, select: &mut impl FnMut( &Sender<Event> ) -> bool ) -> Poll<Result<(), PharErr>>
+	{
 		if self.closed
 		{
 			return Err( ErrorKind::Closed.into() ).into();
 		}
 
 
+		if self.over_limit() == Some( BufferPolicy::Error )
+		{
+			return Err( ErrorKind::BufferFull.into() ).into();
+		}
+
+		ready!( self.poll_limit( cx ) );
+
+
 		// As soon as any is not ready, we are not ready.
 		//
-		// This is a false warning AFAICT. We need to set obs
-		// to None at the end, which is not possible if we have flattened the iterator.
-		//
-		#[allow(clippy::manual_flatten)]
-		//
-		for obs in self.get_mut().observers.iter_mut()
+		for i in self.order()
 		{
-			if let Some( ref mut o ) = obs
+			let opt = &mut self.observers[i];
+
+			if let Some( ref mut obs ) = opt
 			{
-				let res = ready!( Pin::new( o ).poll_ready( cx ) );
+				if !select( obs ) { continue; }
+
+				let res = ready!( Pin::new( &mut *obs ).poll_ready( cx ) );
 
 				// Errors mean disconnected, so drop.
 				//
-				if res.is_err()
+				if let Err( e ) = res
 				{
-					// TODO: why don't we add to free_slots here like below?
-					//
-					*obs = None;
+					Self::report( &mut self.on_error, obs.id(), e );
+					Self::prune( &mut self.free_slots, &self.lifecycle, i, opt );
 				}
 			}
 		}
@@ -219,19 +858,93 @@ impl<Event> Sink<Event> for Pharos<Event> where Event: Clone + 'static + Send
 
 
 
-	fn start_send( self: Pin<&mut Self>, evt: Event ) -> Result<(), Self::Error>
+	// Returns the number of observers that accepted the event.
+	//
+	fn start_send_where( &mut self, evt: Event, select: &mut impl FnMut( &Sender<Event> ) -> bool ) -> Result<usize, PharErr>
 	{
-
 		if self.closed
 		{
 			return Err( ErrorKind::Closed.into() );
 		}
 
 
-		let this = self.get_mut();
+		let mut reach = 0;
 
-		for (i, opt) in this.observers.iter_mut().enumerate()
+		if self.over_limit() == Some( BufferPolicy::DropOldest )
+		{
+			self.shed_oldest();
+		}
+
+		if self.over_limit() == Some( BufferPolicy::DropNewest )
+		{
+			for obs in self.observers.iter_mut().flatten()
+			{
+				if Self::wants( obs, &evt, select ) { obs.count_dropped(); }
+			}
+		}
+
+		else if self.single( select )
+		{
+			#[ cfg( feature = "log" ) ]
+			//
+			log::trace!( "pharos::Pharos<{}>: notifying 1 observer(s)", type_name::<Event>() );
+
+			reach = self.send_to( 0, evt ) as usize;
+		}
+
+		else
 		{
+			reach = self.fan_out( evt, select );
+		}
+
+
+		if self.fair
+		{
+			self.start = self.start.wrapping_add( 1 );
+		}
+
+		Ok( reach )
+	}
+
+
+
+	// Whether there is exactly one observer and it takes this event without a filter or gate. Lots of
+	// observables only ever have one, so this spares them the bookkeeping of fan_out.
+	//
+	fn single( &mut self, select: &mut impl FnMut( &Sender<Event> ) -> bool ) -> bool
+	{
+		match self.observers.as_mut_slice()
+		{
+			[ Some( obs ) ] => obs.takes_all() && select( obs ) && !obs.is_closed(),
+			_               => false                                               ,
+		}
+	}
+
+
+
+	// Steps 1 to 3 of the order of evaluation documented on Pharos. The filter of the sender
+	// applies the gate.
+	//
+	fn wants( obs: &mut Sender<Event>, evt: &Event, select: &mut impl FnMut( &Sender<Event> ) -> bool ) -> bool
+	{
+		select( obs ) && obs.filter( evt )
+	}
+
+
+
+	fn fan_out( &mut self, evt: Event, select: &mut impl FnMut( &Sender<Event> ) -> bool ) -> usize
+	{
+		// We only know an observer is the last one interested in the event once we have checked
+		// all others, so each one gets it's event when the next interested observer is found.
+		// That way the last one can get the original instead of a clone.
+		//
+		let mut previous = None;
+		let mut reach    = 0;
+
+		for i in self.order()
+		{
+			let opt = &mut self.observers[i];
+
 			// if this spot in the vector has a sender
 			//
 			if let Some( obs ) = opt
@@ -240,35 +953,45 @@ impl<Event> Sink<Event> for Pharos<Event> where Event: Clone + 'static + Send
 				//
 				if obs.is_closed()
 				{
-					this.free_slots.push( i );
-
-					*opt = None;
+					Self::prune( &mut self.free_slots, &self.lifecycle, i, opt );
 				}
 
 				// else if it is interested in this event
 				//
-				else if obs.filter( &evt )
+				else if Self::wants( obs, &evt, select )
 				{
-					// if sending fails, remove it
-					//
-					if Pin::new( obs ).start_send( evt.clone() ).is_err()
+					if let Some( p ) = previous.replace( i )
 					{
-						this.free_slots.push( i );
-
-						*opt = None;
+						reach += self.send_to( p, evt.clone() ) as usize;
 					}
 				}
+
+				// the filter panicked.
+				//
+				else if obs.panicked()
+				{
+					Self::report( &mut self.on_error, obs.id(), ErrorKind::Panicked.into() );
+					Self::prune( &mut self.free_slots, &self.lifecycle, i, opt );
+				}
 			}
 		}
 
-		Ok(())
+		if let Some( p ) = previous
+		{
+			reach += self.send_to( p, evt ) as usize;
+		}
+
+		#[ cfg( feature = "log" ) ]
+		//
+		log::trace!( "pharos::Pharos<{}>: notifying {} observer(s)", type_name::<Event>(), reach );
+
+		reach
 	}
 
 
 
-	fn poll_flush( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
+	fn poll_flush_where( &mut self, cx: &mut Context<'_>, select: &mut impl FnMut( &Sender<Event> ) -> bool ) -> Poll<Result<(), PharErr>>
 	{
-
 		if self.closed
 		{
 			return Err( ErrorKind::Closed.into() ).into();
@@ -279,22 +1002,24 @@ impl<Event> Sink<Event> for Pharos<Event> where Event: Clone + 'static + Send
 		// If any return an error, we drop them.
 		//
 		let mut pending = false;
-		let     this    = self.get_mut();
 
-		for (i, opt) in this.observers.iter_mut().enumerate()
+		for i in self.order()
 		{
+			let opt = &mut self.observers[i];
+
 			if let Some( ref mut obs ) = opt
 			{
-				match Pin::new( obs ).poll_flush( cx )
+				if !select( obs ) { continue; }
+
+				match Pin::new( &mut *obs ).poll_flush( cx )
 				{
 					Poll::Pending       => pending = true ,
 					Poll::Ready(Ok(_))  => continue       ,
 
-					Poll::Ready(Err(_)) =>
+					Poll::Ready(Err(e)) =>
 					{
-						this.free_slots.push( i );
-
-						*opt = None;
+						Self::report( &mut self.on_error, obs.id(), e );
+						Self::prune( &mut self.free_slots, &self.lifecycle, i, opt );
 					}
 				}
 			}
@@ -303,6 +1028,35 @@ impl<Event> Sink<Event> for Pharos<Event> where Event: Clone + 'static + Send
 		if pending { Poll::Pending }
 		else       { Ok(()).into() }
 	}
+}
+
+
+
+// See the documentation on Channel for how poll functions work for the channels we use.
+//
+impl<Event> Sink<Event> for Pharos<Event> where Event: Clone + 'static + Send
+{
+	type Error = PharErr;
+
+
+	fn poll_ready( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
+	{
+		self.get_mut().poll_ready_where( cx, &mut |_| true )
+	}
+
+
+
+	fn start_send( self: Pin<&mut Self>, evt: Event ) -> Result<(), Self::Error>
+	{
+		self.get_mut().start_send_where( evt, &mut |_| true ).map( |_| () )
+	}
+
+
+
+	fn poll_flush( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Result<(), Self::Error>>
+	{
+		self.get_mut().poll_flush_where( cx, &mut |_| true )
+	}
 
 
 
@@ -323,6 +1077,9 @@ impl<Event> Sink<Event> for Pharos<Event> where Event: Clone + 'static + Send
 
 		let this = self.get_mut();
 
+		this.unsubscribe_all( UnsubscribeReason::Closed );
+		this.lifecycle.close();
+
 		for (i, opt) in this.observers.iter_mut().enumerate()
 		{
 			if let Some( ref mut obs ) = opt
@@ -331,9 +1088,7 @@ impl<Event> Sink<Event> for Pharos<Event> where Event: Clone + 'static + Send
 
 				if res.is_err()
 				{
-					this.free_slots.push( i );
-
-					*opt = None;
+					Self::prune( &mut this.free_slots, &this.lifecycle, i, opt );
 				}
 			}
 		}
@@ -362,7 +1117,10 @@ mod tests
 	// - ✔ start_send verify message arrives
 	// - ✔ start_send drop disconnected channel
 	// - ✔ start_send filter message
+	// - ✔ start_send with a single observer, with and without filter, and after it disconnected
 	// - ✔ poll_flush drop on error
+	// - ✔ flush_all drop on error and return closed if the pharos is closed
+	// - ✔ is_observed without and with observers, after they close and after reconnecting
 	//
 	// TODO: fix the assert_matches ambiguity. Can we use assert!( matches!() ) from std?
 	//
@@ -436,6 +1194,31 @@ mod tests
 	}
 
 
+	// is_observed only counts observers that are still listening.
+	//
+	#[async_std::test]
+	//
+	async fn is_observed()
+	{
+		let mut ph = Pharos::<bool>::default();
+
+			assert!( !ph.is_observed() );
+
+		let mut a = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+			assert!( ph.is_observed() );
+
+		a.close();
+
+			assert!( !ph.is_observed() );
+
+		let mut b = ph.observe( ObserveConfig::default().reconnectable() ).await.expect( "observe" );
+		let _c    = b.reconnect().expect( "reconnect" );
+
+			assert!( ph.is_observed() );
+	}
+
+
 	// observe: Make sure we are reusing slots
 	//
 	#[async_std::test]
@@ -521,18 +1304,18 @@ mod tests
 		let mut full = ph.observe( Channel::Bounded  ( 1  ).into() ).await.expect( "observe" );
 		let _unbound = ph.observe( Channel::Unbounded      .into() ).await.expect( "observe" );
 
-		poll_fn( move |mut cx|
+		poll_fn( move |cx|
 		{
 			let mut ph = Pin::new( &mut ph );
 
-				crate::assert_matches!( ph.as_mut().poll_ready( &mut cx ), Poll::Ready( Ok(_) ) );
+				crate::assert_matches!( ph.as_mut().poll_ready( cx ), Poll::Ready( Ok(_) ) );
 				assert!( ph.as_mut().start_send( true ).is_ok() );
 
-				crate::assert_matches!( ph.as_mut().poll_ready( &mut cx ), Poll::Pending );
+				crate::assert_matches!( ph.as_mut().poll_ready( cx ), Poll::Pending );
 
 				assert_eq!( Pin::new( &mut full ).poll_next(cx), Poll::Ready(Some(true)));
 
-				crate::assert_matches!( ph.as_mut().poll_ready( &mut cx ), Poll::Ready( Ok(_) ) );
+				crate::assert_matches!( ph.as_mut().poll_ready( cx ), Poll::Ready( Ok(_) ) );
 
 			().into()
 
@@ -558,9 +1341,9 @@ mod tests
 		drop( full );
 
 
-		poll_fn( move |mut cx|
+		poll_fn( move |cx|
 		{
-			crate::assert_matches!( ph.as_mut().poll_ready( &mut cx ), Poll::Ready( Ok(_) ) );
+			crate::assert_matches!( ph.as_mut().poll_ready( cx ), Poll::Ready( Ok(_) ) );
 
 			assert!( ph.observers[1].is_none() );
 
@@ -577,7 +1360,7 @@ mod tests
 	//
 	fn poll_ready_closed()
 	{
-		block_on( poll_fn( move |mut cx|
+		block_on( poll_fn( move |cx|
 		{
 			let mut ph = Pharos::<bool>::default();
 
@@ -585,7 +1368,7 @@ mod tests
 
 				crate::assert_matches!( ph.as_mut().poll_close( cx ), Poll::Ready(Ok(())) );
 
-			let res = ph.as_mut().poll_ready( &mut cx );
+			let res = ph.as_mut().poll_ready( cx );
 
 				crate::assert_matches!( res, Poll::Ready( Err(_) ) );
 
@@ -614,11 +1397,11 @@ mod tests
 		let mut full = ph.observe( Channel::Bounded  ( 1  ).into() ).await.expect( "observe" );
 		let _unbound = ph.observe( Channel::Unbounded      .into() ).await.expect( "observe" );
 
-		poll_fn( move |mut cx|
+		poll_fn( move |cx|
 		{
 			let mut ph = Pin::new( &mut ph );
 
-			crate::assert_matches!( ph.as_mut().poll_ready( &mut cx ), Poll::Ready( Ok(_) ) );
+			crate::assert_matches!( ph.as_mut().poll_ready( cx ), Poll::Ready( Ok(_) ) );
 			assert!( ph.as_mut().start_send( 3 ).is_ok() );
 
 			assert_eq!( Pin::new( &mut full ).poll_next(cx), Poll::Ready(Some(3)));
@@ -630,6 +1413,36 @@ mod tests
 
 
 
+	// a single observer without filter takes the fast path, with a filter or once it's closed it doesn't.
+	//
+	#[async_std::test]
+	//
+	async fn start_send_single()
+	{
+		use futures::StreamExt;
+
+		let mut ph     = Pharos::<usize>::default();
+		let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+		ph.send( 3 ).await.expect( "send" );
+
+		assert_eq!( Some( 3 ), events.next().await );
+
+		drop( events );
+		ph.send( 4 ).await.expect( "send" );
+
+		assert_eq!( 1, ph.free_slots.len() );
+
+		let mut events = ph.observe( Filter::Pointer( |n| *n > 4 ).into() ).await.expect( "observe" );
+
+		ph.send( 4 ).await.expect( "send" );
+		ph.send( 5 ).await.expect( "send" );
+
+		assert_eq!( Some( 5 ), events.next().await );
+	}
+
+
+
 	// pharos drops closed observers.
 	//
 	#[async_std::test]
@@ -646,9 +1459,9 @@ mod tests
 
 		drop( full );
 
-		poll_fn( move |mut cx|
+		poll_fn( move |cx|
 		{
-			crate::assert_matches!( ph.as_mut().poll_flush( &mut cx ), Poll::Ready( Ok(_) ) );
+			crate::assert_matches!( ph.as_mut().poll_flush( cx ), Poll::Ready( Ok(_) ) );
 
 			assert!( ph.observers[1].is_none() );
 			().into()
@@ -658,5 +1471,25 @@ mod tests
 
 
 
+	// flush_all drops disconnected observers and errors when we are closed.
+	//
+	#[async_std::test]
+	//
+	async fn flush_all()
+	{
+		let mut ph = Pharos::<bool>::default();
+
+		let _open = ph.observe( Channel::Bounded( 1 ).into() ).await.expect( "observe" );
+		let gone  = ph.observe( Channel::Unbounded   .into() ).await.expect( "observe" );
+
+		ph.send( true ).await.expect( "send" );
+		drop( gone );
+
+			assert!( ph.flush_all().await.is_ok() );
+			assert!( ph.observers[1].is_none()    );
+
+		ph.close().await.expect( "close" );
 
+			assert_eq!( ErrorKind::Closed, ph.flush_all().await.unwrap_err().kind() );
+	}
 }
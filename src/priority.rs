@@ -0,0 +1,194 @@
+use crate :: { import::*, Events, events::Adapter };
+use std   :: { collections::BinaryHeap, cmp::{ Ordering, Reverse } };
+
+
+/// The receiving end of [Channel::Priority](crate::Channel::Priority). It pulls events from a bounded
+/// channel into a binary heap and yields the event with the highest priority first.
+///
+/// The heap holds at most `capacity` events, so at most `capacity` events are re-ordered at any time.
+/// Events with equal priority are yielded in the order they were sent.
+//
+pub(crate) struct PriorityReceiver<Event> where Event: Clone + 'static + Send
+{
+	rx      : FutReceiver<Event>               ,
+	heap    : BinaryHeap< Prioritized<Event> > ,
+	key     : fn(&Event) -> u64                ,
+	capacity: usize                            ,
+	sequence: u64                              ,
+	done    : bool                             ,
+}
+
+
+impl<Event> PriorityReceiver<Event> where Event: Clone + 'static + Send
+{
+	pub(crate) fn new( rx: FutReceiver<Event>, key: fn(&Event) -> u64, capacity: usize ) -> Self
+	{
+		Self
+		{
+			rx                                              ,
+			key                                             ,
+			capacity                                        ,
+			heap    : BinaryHeap::with_capacity( capacity ) ,
+			sequence: 0                                     ,
+			done    : false                                 ,
+		}
+	}
+
+
+	pub(crate) fn close( &mut self )
+	{
+		self.rx.close();
+	}
+}
+
+
+
+// We never pin project into the events, so there is no reason to require `Event: Unpin`.
+//
+impl<Event> Unpin for PriorityReceiver<Event> where Event: Clone + 'static + Send {}
+
+
+
+impl<Event> Stream for PriorityReceiver<Event> where Event: Clone + 'static + Send
+{
+	type Item = Event;
+
+	fn poll_next( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll< Option<Self::Item> >
+	{
+		let this = self.get_mut();
+
+		// Pull in everything that is available, so we can choose the highest priority among it.
+		//
+		while !this.done && this.heap.len() < this.capacity
+		{
+			match Pin::new( &mut this.rx ).poll_next( cx )
+			{
+				Poll::Ready( Some(event) ) =>
+				{
+					let priority = (this.key)( &event );
+
+					this.heap.push( Prioritized{ priority, sequence: Reverse( this.sequence ), event } );
+					this.sequence += 1;
+				}
+
+				Poll::Ready( None ) => this.done = true,
+				Poll::Pending       => break           ,
+			}
+		}
+
+		match this.heap.pop()
+		{
+			Some( p )         => Poll::Ready( Some( p.event ) ) ,
+			None if this.done => Poll::Ready( None )            ,
+			None              => Poll::Pending                  ,
+		}
+	}
+}
+
+
+
+// Ordered by priority, then by reverse order of arrival so that the heap is FIFO for equal priorities.
+//
+struct Prioritized<Event>
+{
+	priority: u64          ,
+	sequence: Reverse<u64> ,
+	event   : Event        ,
+}
+
+
+impl<Event> PartialEq for Prioritized<Event>
+{
+	fn eq( &self, other: &Self ) -> bool
+	{
+		self.cmp( other ) == Ordering::Equal
+	}
+}
+
+impl<Event> Eq for Prioritized<Event> {}
+
+
+impl<Event> PartialOrd for Prioritized<Event>
+{
+	fn partial_cmp( &self, other: &Self ) -> Option<Ordering>
+	{
+		Some( self.cmp( other ) )
+	}
+}
+
+
+impl<Event> Ord for Prioritized<Event>
+{
+	fn cmp( &self, other: &Self ) -> Ordering
+	{
+		( self.priority, self.sequence ).cmp( &( other.priority, other.sequence ) )
+	}
+}
+
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// [Close](Events::close) the stream and yield the events that are still pending in order of `key`,
+	/// highest first, like [Channel::Priority](crate::Channel::Priority). Pending events with equal keys are
+	/// yielded in the order they were sent. Use this to deliver eg. errors before informational events on shutdown.
+	///
+	/// The observable stops sending right away. Before the first event is yielded, all pending events are
+	/// read into memory to sort them. On an unbounded channel that can be a lot of events.
+	//
+	pub fn close_prioritized<K, F>( mut self, key: F ) -> Events<Event>
+
+		where K: Ord                                   ,
+		      F: FnMut( &Event ) -> K + Send + 'static ,
+	{
+		self.close();
+
+		Events::from_adapter( self, |events| ClosePrioritized{ events, key, pending: Vec::new(), sorted: None } )
+	}
+}
+
+
+
+struct ClosePrioritized<Event, F> where Event: Clone + 'static + Send
+{
+	events : Events<Event>             ,
+	key    : F                         ,
+	pending: Vec<Event>                ,
+	sorted : Option< VecDeque<Event> > ,
+}
+
+
+
+impl<Event, K, F> Adapter<Event> for ClosePrioritized<Event, F>
+
+	where Event: Clone + 'static + Send     ,
+	      K    : Ord                        ,
+	      F    : FnMut( &Event ) -> K + Send ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Event> >
+	{
+		if self.sorted.is_none()
+		{
+			while let Some( evt ) = ready!( Pin::new( &mut self.events ).poll_next( cx ) )
+			{
+				self.pending.push( evt );
+			}
+
+			let mut pending = std::mem::take( &mut self.pending );
+			let key         = &mut self.key;
+
+			// This sort is stable, so FIFO is kept for equal keys.
+			//
+			pending.sort_by_cached_key( |evt| Reverse( key( evt ) ) );
+
+			self.sorted = Some( pending.into() );
+		}
+
+		Poll::Ready( self.sorted.as_mut().and_then( VecDeque::pop_front ) )
+	}
+
+
+	// We are already closed.
+	//
+	fn close( &mut self ) {}
+}
@@ -0,0 +1,250 @@
+use crate :: { import::*, PharErr, ErrorKind, events::{ Relay, Stats } };
+use std   :: { task::Waker, sync::{ MutexGuard, PoisonError }, panic::AssertUnwindSafe };
+
+
+/// Combines a pending event with a new one for [Channel::Reduce](crate::Channel::Reduce). It's shared, so a
+/// reconnected observer keeps using the same function.
+//
+pub(crate) struct Reducer<Event>( Arc<SyncMutex< ReduceFn<Event> >> );
+
+type ReduceFn<Event> = Box< dyn FnMut( Event, Event ) -> Event + Send >;
+
+
+impl<Event> Reducer<Event>
+{
+	pub(crate) fn new( f: impl FnMut( Event, Event ) -> Event + Send + 'static ) -> Self
+	{
+		Self( Arc::new( SyncMutex::new( Box::new( f ) ) ) )
+	}
+
+
+	fn reduce( &self, pending: Event, evt: Event ) -> Event
+	{
+		(self.0.lock().unwrap_or_else( PoisonError::into_inner ))( pending, evt )
+	}
+}
+
+
+impl<Event> Clone for Reducer<Event>
+{
+	fn clone( &self ) -> Self
+	{
+		Self( self.0.clone() )
+	}
+}
+
+
+impl<Event> fmt::Debug for Reducer<Event>
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		write!( f, "pharos::Reducer<{}>", type_name::<Event>() )
+	}
+}
+
+
+
+/// Create the reducing buffer for [Channel::Reduce](crate::Channel::Reduce). It holds at most one event.
+/// When a new event arrives while one is pending, the two are combined by `reducer`.
+//
+pub(crate) fn channel<Event>( reducer: Reducer<Event>, stats: Arc<Stats> ) -> (ReduceSender<Event>, ReduceReceiver<Event>)
+
+	where Event: Clone + 'static + Send
+{
+	let slot = Arc::new( SyncMutex::new( Slot{ pending: None, waker: None, closed: false, done: false } ) );
+
+	( ReduceSender{ slot: slot.clone(), reducer, stats }, ReduceReceiver{ slot } )
+}
+
+
+
+struct Slot<Event>
+{
+	pending: Option<Event> ,
+	waker  : Option<Waker> ,
+
+	// The receiver is closed or dropped.
+	//
+	closed : bool          ,
+
+	// The sender is closed or dropped.
+	//
+	done   : bool          ,
+}
+
+
+impl<Event> Slot<Event>
+{
+	// The reducer doesn't run under this lock, so it's only poisoned if something is very wrong. The
+	// slot is still consistent then, so keep going.
+	//
+	fn lock( slot: &SyncMutex< Slot<Event> > ) -> MutexGuard<'_, Slot<Event>>
+	{
+		slot.lock().unwrap_or_else( PoisonError::into_inner )
+	}
+
+
+	fn wake( &mut self )
+	{
+		if let Some( w ) = self.waker.take() { w.wake(); }
+	}
+}
+
+
+
+pub(crate) struct ReduceSender<Event>
+{
+	slot   : Arc<SyncMutex< Slot<Event> >> ,
+	reducer: Reducer<Event>                ,
+	stats  : Arc<Stats>                    ,
+}
+
+
+impl<Event> ReduceSender<Event>
+{
+	fn check( &self ) -> Result<(), PharErr>
+	{
+		match Slot::lock( &self.slot ).closed
+		{
+			true  => Err( ErrorKind::SendError.into() ),
+			false => Ok(())                            ,
+		}
+	}
+}
+
+
+// The buffer never fills up, so the sender never has to wait.
+//
+impl<Event> Relay<Event> for ReduceSender<Event> where Event: Clone + 'static + Send
+{
+	fn is_closed( &self ) -> bool
+	{
+		Slot::lock( &self.slot ).closed
+	}
+
+
+	fn poll_ready( &mut self, _cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		self.check().into()
+	}
+
+
+	// The reducer runs without holding the lock of the slot, so if it panics, the receiver can still
+	// use the slot. The pending event it was given is lost then, so it's counted as dropped.
+	//
+	fn start_send( &mut self, evt: Event ) -> Result<bool, PharErr>
+	{
+		let pending =
+		{
+			let mut slot = Slot::lock( &self.slot );
+
+			if slot.closed
+			{
+				return Err( ErrorKind::SendError.into() );
+			}
+
+			slot.pending.take()
+		};
+
+		let evt = match pending
+		{
+			Some( pending ) => match std::panic::catch_unwind( AssertUnwindSafe( || self.reducer.reduce( pending, evt ) ) )
+			{
+				Ok ( evt   ) => evt,
+				Err( panic ) => { self.stats.lose(); std::panic::resume_unwind( panic ) }
+			},
+
+			// Only count the event when the buffer was empty, since it holds at most one.
+			//
+			None => { self.stats.push(); evt }
+		};
+
+		let mut slot = Slot::lock( &self.slot );
+
+		slot.pending = Some( evt );
+		slot.wake();
+
+		Ok( true )
+	}
+
+
+	fn poll_flush( &mut self, _cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		self.check().into()
+	}
+
+
+	fn poll_close( &mut self, _cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		let mut slot = Slot::lock( &self.slot );
+
+		slot.done = true;
+		slot.wake();
+
+		Ok(()).into()
+	}
+}
+
+
+impl<Event> Drop for ReduceSender<Event>
+{
+	fn drop( &mut self )
+	{
+		let mut slot = Slot::lock( &self.slot );
+
+		slot.done = true;
+		slot.wake();
+	}
+}
+
+
+
+pub(crate) struct ReduceReceiver<Event>
+{
+	slot: Arc<SyncMutex< Slot<Event> >>,
+}
+
+
+impl<Event> ReduceReceiver<Event>
+{
+	/// Stop receiving new events. The pending one is still delivered.
+	//
+	pub(crate) fn close( &mut self )
+	{
+		Slot::lock( &self.slot ).closed = true;
+	}
+}
+
+
+impl<Event> Stream for ReduceReceiver<Event>
+{
+	type Item = Event;
+
+	fn poll_next( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll< Option<Self::Item> >
+	{
+		let mut slot = Slot::lock( &self.slot );
+
+		if let Some( evt ) = slot.pending.take()
+		{
+			return Poll::Ready( Some( evt ) );
+		}
+
+		if slot.closed || slot.done
+		{
+			return Poll::Ready( None );
+		}
+
+		slot.waker = Some( cx.waker().clone() );
+
+		Poll::Pending
+	}
+}
+
+
+impl<Event> Drop for ReduceReceiver<Event>
+{
+	fn drop( &mut self )
+	{
+		Slot::lock( &self.slot ).closed = true;
+	}
+}
@@ -0,0 +1,183 @@
+use crate :: { import::*, Pharos, PharErr, Events, ObserveConfig, events::{ Tx, Relay } };
+
+
+impl<T, E> Pharos< Result<T, E> >
+
+	where T: Clone + 'static + Send ,
+	      E: Clone + 'static + Send ,
+{
+	/// Observe a pharos of `Result` events on two streams: `Ok` values go to the first, `Err` values to the second.
+	/// Every event is delivered to exactly one of them, so consumers don't have to match on the `Result`.
+	///
+	/// Both streams get a channel of the type set in `options`. The filter of `options` is applied to the `Result`
+	/// before routing. Like for [observe_expand](Pharos::observe_expand), the options for priority, backfill and
+	/// reconnecting are ignored.
+	///
+	/// With a bounded channel, notifying waits until the stream an event goes to has room for it, a full
+	/// stream of errors doesn't hold back oks that come before the next error. When one of the streams is dropped
+	/// or [closed](Events::close), events for it are discarded while the other keeps receiving. The observer
+	/// is removed once both are gone. When the pharos is closed, an event that did not fit in it's channel is
+	/// dropped. Events that are [gated](crate::ObserveConfig::gate) are counted on both.
+	///
+	/// ```
+	/// use pharos::*;
+	///
+	/// # async fn task( mut pharos: Pharos<Result<usize, String>> ) -> Result<(), PharErr> {
+	/// let (values, errors) = pharos.observe_results( ObserveConfig::default() ).await?;
+	/// # Ok(()) }
+	/// ```
+	//
+	pub async fn observe_results( &mut self, options: ObserveConfig< Result<T, E> > ) -> Result< (Events<T>, Events<E>), PharErr >
+	{
+		self.check( &options )?;
+
+		let channel  = options.channel;
+		let mut errs = None;
+
+		let (oks, sender) = Events::with_relay( options, |ok, stats|
+		{
+			let (err, events) = Events::with_stats( channel, stats );
+
+			errs = Some( events );
+
+			Split{ ok, err, pending: None }
+		});
+
+		self.register( sender );
+
+		Ok(( oks, errs.expect( "with_relay calls make" ) ))
+	}
+}
+
+
+
+struct Split<T, E> where T: Clone + 'static + Send, E: Clone + 'static + Send
+{
+	ok     : Tx<T>                  ,
+	err    : Tx<E>                  ,
+
+	// An event that is waiting for room in it's channel.
+	//
+	pending: Option< Result<T, E> > ,
+}
+
+
+impl<T, E> Split<T, E> where T: Clone + 'static + Send, E: Clone + 'static + Send
+{
+	// Send the pending event once it's channel has room.
+	//
+	fn drain( &mut self, cx: &mut Context<'_> ) -> Poll<()>
+	{
+		self.pending = match self.pending.take()
+		{
+			Some( Ok (evt) ) => Self::send( &mut self.ok , evt, cx ).map( Ok  ),
+			Some( Err(evt) ) => Self::send( &mut self.err, evt, cx ).map( Err ),
+			None             => None,
+		};
+
+		match self.pending
+		{
+			Some(_) => Poll::Pending    ,
+			None    => Poll::Ready( () ),
+		}
+	}
+
+
+	// Send `evt` if the channel has room, otherwise give it back. Events for a channel that
+	// is closed are discarded.
+	//
+	fn send<X>( tx: &mut Tx<X>, evt: X, cx: &mut Context<'_> ) -> Option<X>
+
+		where X: Clone + 'static + Send
+	{
+		if tx.is_closed()
+		{
+			return None;
+		}
+
+		match Pin::new( &mut *tx ).poll_ready( cx )
+		{
+			Poll::Pending         => Some( evt ),
+			Poll::Ready( Err(_) ) => None       ,
+
+			Poll::Ready( Ok(()) ) =>
+			{
+				let _ = Pin::new( tx ).start_send( evt );
+				None
+			}
+		}
+	}
+
+
+	// Flush a channel, unless it's closed.
+	//
+	fn flush<X>( tx: &mut Tx<X>, cx: &mut Context<'_> ) -> Poll<()>
+
+		where X: Clone + 'static + Send
+	{
+		if tx.is_closed()
+		{
+			return Poll::Ready( () );
+		}
+
+		Pin::new( tx ).poll_flush( cx ).map( |_| () )
+	}
+}
+
+
+impl<T, E> Relay< Result<T, E> > for Split<T, E>
+
+	where T: Clone + 'static + Send ,
+	      E: Clone + 'static + Send ,
+{
+	fn is_closed( &self ) -> bool
+	{
+		self.ok.is_closed() && self.err.is_closed()
+	}
+
+
+	fn poll_ready( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		self.drain( cx ).map( Ok )
+	}
+
+
+	fn start_send( &mut self, evt: Result<T, E> ) -> Result<bool, PharErr>
+	{
+		self.pending = Some( evt );
+
+		Ok( true )
+	}
+
+
+	fn poll_flush( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		ready!( self.drain( cx ) );
+
+		let ok  = Self::flush( &mut self.ok , cx );
+		let err = Self::flush( &mut self.err, cx );
+
+		ready!( ok  );
+		ready!( err );
+
+		Ok(()).into()
+	}
+
+
+	// Don't wait for the observer to make room, the pharos is going away.
+	//
+	fn poll_close( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		let _ = self.drain( cx );
+
+		self.pending = None;
+
+		let ok  = Pin::new( &mut self.ok  ).poll_close( cx );
+		let err = Pin::new( &mut self.err ).poll_close( cx );
+
+		ready!( ok  )?;
+		ready!( err )?;
+
+		Ok(()).into()
+	}
+}
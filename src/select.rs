@@ -0,0 +1,84 @@
+use crate :: { import::*, Events, events::Adapter };
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Merge several streams into one, tagging every event with the label of the stream it came from. Use this
+	/// instead of a `select!` with a branch per stream when you need to know which observable produced an event.
+	///
+	/// The streams are polled round robin. The one polled first moves on with every event, so a stream that always
+	/// has events ready can not starve the others. Events of a single stream are delivered in order. A stream that
+	/// ends is removed, the result ends when all of them have ended. [close](Events::close) closes all of them, pending
+	/// events can still be read.
+	///
+	/// Events are only taken out of a stream when they are returned, so dropping the future of
+	/// [next](futures::StreamExt::next), eg. in a branch of `select!` that didn't win, never loses an event.
+	///
+	/// ```
+	/// use pharos::*;
+	///
+	/// # fn task( clicks: Events<usize>, keys: Events<usize> ) {
+	/// let input = Events::select_labeled( vec![ ( "mouse", clicks ), ( "keyboard", keys ) ] );
+	/// # }
+	/// ```
+	//
+	pub fn select_labeled<L>( streams: Vec<( L, Events<Event> )> ) -> Events<( L, Event )>
+
+		where L: Clone + Send + 'static ,
+	{
+		Events::detached( Select{ streams, next: 0 } )
+	}
+}
+
+
+
+struct Select<L, Event> where Event: Clone + 'static + Send
+{
+	streams: Vec<( L, Events<Event> )> ,
+
+	// The stream that is polled first.
+	//
+	next   : usize                     ,
+}
+
+
+
+impl<L, Event> Adapter<( L, Event )> for Select<L, Event>
+
+	where L    : Clone + Send + 'static   ,
+	      Event: Clone + 'static + Send ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<( L, Event )> >
+	{
+		let mut i = 0;
+
+		while i < self.streams.len()
+		{
+			let idx = ( self.next + i ) % self.streams.len();
+
+			match Pin::new( &mut self.streams[idx].1 ).poll_next( cx )
+			{
+				Poll::Ready( Some(evt) ) =>
+				{
+					self.next = idx + 1;
+
+					return Poll::Ready( Some(( self.streams[idx].0.clone(), evt )) );
+				}
+
+				// The stream at idx is replaced by the next one, so don't move on.
+				//
+				Poll::Ready( None ) => { self.streams.remove( idx ); if idx < self.next { self.next -= 1; } }
+				Poll::Pending       => i += 1,
+			}
+		}
+
+		if self.streams.is_empty() { Poll::Ready( None ) }
+		else                       { Poll::Pending       }
+	}
+
+
+	fn close( &mut self )
+	{
+		for (_, events) in &mut self.streams { events.close(); }
+	}
+}
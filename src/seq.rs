@@ -0,0 +1,93 @@
+use crate :: { import::*, Pharos, PharErr, Events, ObserveConfig, events::{ Tx, Relay, Stats } };
+
+
+/// An event tagged with a sequence number, see [Pharos::observe_sequenced].
+//
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+//
+pub struct Seq<Event>
+{
+	/// The number of this event for this observer, starting at 0.
+	//
+	pub seq: u64,
+
+	/// The event.
+	//
+	pub event: Event,
+}
+
+
+
+impl<Event> Pharos<Event> where Event: Clone + 'static + Send
+{
+	/// Observe events tagged with a sequence number. Every event is numbered when it's notified, counting only
+	/// the events this observer is interested in (the ones that pass it's filter).
+	///
+	/// Events that are notified but not delivered, like the ones skipped by a [gate](crate::ObserveConfig::gate),
+	/// still take a number. The consumer sees a gap in the sequence and knows exactly how many it missed.
+	/// Like for [observe_expand](Pharos::observe_expand), the options for priority, backfill and reconnecting
+	/// are ignored.
+	//
+	pub async fn observe_sequenced( &mut self, options: ObserveConfig<Event> ) -> Result< Events<Seq<Event>>, PharErr >
+	{
+		self.check( &options )?;
+
+		let (events, sender) = Events::with_relay( options, |tx, stats| Sequence{ tx, stats, sent: 0 } );
+
+		self.register( sender );
+
+		Ok( events )
+	}
+}
+
+
+
+struct Sequence<Event> where Event: Clone + 'static + Send
+{
+	tx   : Tx<Seq<Event>> ,
+	stats: Arc<Stats>     ,
+	sent : u64            ,
+}
+
+
+
+impl<Event> Relay<Event> for Sequence<Event> where Event: Clone + 'static + Send
+{
+	fn is_closed( &self ) -> bool
+	{
+		self.tx.is_closed()
+	}
+
+
+	fn poll_ready( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		Pin::new( &mut self.tx ).poll_ready( cx )
+	}
+
+
+	// Dropped events never make it here, so they are added in. Events that were shed after they were
+	// sent already have a number.
+	//
+	fn start_send( &mut self, event: Event ) -> Result<bool, PharErr>
+	{
+		let seq = self.sent + self.stats.unsent.load( Ordering::Relaxed );
+
+		self.tx.deliver( Seq{ seq, event } )?;
+
+		self.sent += 1;
+
+		Ok( true )
+	}
+
+
+	fn poll_flush( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		Pin::new( &mut self.tx ).poll_flush( cx )
+	}
+
+
+	fn poll_close( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		Pin::new( &mut self.tx ).poll_close( cx )
+	}
+}
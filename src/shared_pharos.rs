@@ -1,14 +1,29 @@
-use crate::{ import::*, Pharos, PharErr, Observable, Observe, ObserveConfig, Events };
+use crate::{ import::*, Pharos, PharErr, Observable, Observe, ObserveConfig, Events, SyncEvent, GroupId, Seq, Lossy, ObserverSet, LifecycleEvent, ObserverId, CapacityReport };
 
 
 /// A handy wrapper that uses a futures aware mutex to allow using Pharos from a shared
 /// reference.
+///
+/// All methods take `&self`, so you can observe and notify an object that is shared behind an `Arc`
+/// without any external locking. The type is also cheap to clone, all clones refer to the same observers.
+///
+/// The lock is held for the duration of a notification, including while waiting for back pressure,
+/// so if you have a single owner doing all the notifying, [Pharos] with it's `&mut self` API is
+/// the faster option.
+///
+/// ## Order of events
+///
+/// Because of that lock, notifications from concurrent tasks never interleave: one is delivered to all
+/// observers before the next one starts. So all observers see events in the same total order, the order
+/// in which the tasks got the lock. This is always the case, there is no faster unordered mode. The price
+/// is that a task that notifies waits for the notification of any other task to complete, including it's
+/// back pressure. If observers don't need a common order, you can give each producer it's own [Pharos].
 //
 #[ derive( Debug, Clone ) ]
 //
 pub struct SharedPharos<Event> where Event: 'static + Clone + Send
 {
-	pharos: Arc<Mutex< Pharos<Event> >>,
+	pub(crate) pharos: Arc<Mutex< Pharos<Event> >>,
 }
 
 
@@ -22,23 +37,277 @@ impl<Event> SharedPharos<Event> where Event: 'static + Clone + Send
 	}
 
 
-	/// Notify observers.
+	/// Whether any observer is still listening. See [Pharos::is_observed].
 	//
-	pub async fn notify( &self, evt: Event ) -> Result<(), PharErr>
+	pub async fn is_observed( &self ) -> bool
+	{
+		self.pharos.lock().await.is_observed()
+	}
+
+
+	/// Returns the number of actual observers that are still listening. See [Pharos::num_observers].
+	//
+	pub async fn num_observers( &self ) -> usize
+	{
+		self.pharos.lock().await.num_observers()
+	}
+
+
+	/// Start measuring how the buffers of the observers are used. See [Pharos::start_capacity_profiling].
+	//
+	pub async fn start_capacity_profiling( &self )
+	{
+		self.pharos.lock().await.start_capacity_profiling()
+	}
+
+
+	/// Report how the buffers of the observers were used. See [Pharos::capacity_report].
+	//
+	pub async fn capacity_report( &self ) -> CapacityReport
+	{
+		self.pharos.lock().await.capacity_report()
+	}
+
+
+	/// Remove the observers that went away. See [Pharos::gc].
+	//
+	pub async fn gc( &self ) -> usize
+	{
+		self.pharos.lock().await.gc()
+	}
+
+
+	/// Returns the size of the vector used to store the observers. See [Pharos::storage_len].
+	//
+	pub async fn storage_len( &self ) -> usize
+	{
+		self.pharos.lock().await.storage_len()
+	}
+
+
+	/// Notify observers, returning how many accepted the event. See [Pharos::notify].
+	//
+	pub async fn notify( &self, evt: Event ) -> Result<usize, PharErr>
 	{
 		let mut ph = self.pharos.lock().await;
 
-		ph.send( evt ).await
+		ph.notify( evt ).await
 	}
 
 
-	/// Start Observing this Pharos object.
+	/// Flush all observers. See [Pharos::flush_all].
+	//
+	pub async fn flush_all( &self ) -> Result<(), PharErr>
+	{
+		let mut ph = self.pharos.lock().await;
+
+		ph.flush_all().await
+	}
+
+
+	/// The number of events that are buffered but not yet consumed, for every observer.
+	/// See [Pharos::pending_len_per_observer].
+	//
+	pub async fn pending_len_per_observer( &self ) -> Vec<( ObserverId, usize )>
+	{
+		self.pharos.lock().await.pending_len_per_observer()
+	}
+
+
+	/// Call `handler` when sending to an observer fails. See [Pharos::on_error].
+	//
+	pub async fn on_error( &self, handler: impl FnMut( ObserverId, PharErr ) + Send + 'static )
+	{
+		self.pharos.lock().await.on_error( handler );
+	}
+
+
+	/// Unsubscribe the observer with this id. See [Pharos::unsubscribe].
+	//
+	pub async fn unsubscribe( &self, id: ObserverId ) -> bool
+	{
+		self.pharos.lock().await.unsubscribe( id )
+	}
+
+
+	/// Notify only the observers in `group`, returning how many accepted the event. See [Pharos::notify_group].
+	//
+	pub async fn notify_group( &self, group: GroupId, evt: &Event ) -> Result<usize, PharErr>
+	{
+		let mut ph = self.pharos.lock().await;
+
+		ph.notify_group( group, evt ).await
+	}
+
+
+	/// Notify all observers if anyone is listening and `cond` returns true, returning how many accepted the event.
+	/// See [Pharos::notify_if].
+	//
+	pub async fn notify_if( &self, evt: &Event, cond: impl FnOnce() -> bool ) -> Result<usize, PharErr>
+	{
+		let mut ph = self.pharos.lock().await;
+
+		ph.notify_if( evt, cond ).await
+	}
+
+
+	/// Observe the subscriptions of this pharos. See [Pharos::lifecycle_events].
+	//
+	pub async fn lifecycle_events( &self ) -> Events<LifecycleEvent>
+	{
+		self.pharos.lock().await.lifecycle_events()
+	}
+
+
+	/// Replace all observers by the ones in `new` and return the current ones. See [Pharos::swap_observers].
+	//
+	pub async fn swap_observers( &self, new: ObserverSet<Event> ) -> ObserverSet<Event>
+	{
+		let mut ph = self.pharos.lock().await;
+
+		ph.swap_observers( new )
+	}
+
+
+	/// Close all observers. Pending events can still be read by observers, after which their streams end.
+	/// From then on [observe_shared](SharedPharos::observe_shared) and [notify](SharedPharos::notify) will
+	/// return an error of kind [ErrorKind::Closed](crate::ErrorKind::Closed), rather than handing out a
+	/// subscription that would never receive any events.
+	///
+	/// This is the equivalent of calling [SinkExt::close](https://docs.rs/futures-preview/0.3.0-alpha.19/futures/sink/trait.SinkExt.html#method.close)
+	/// on [Pharos].
+	//
+	pub async fn close( &self ) -> Result<(), PharErr>
+	{
+		let mut ph = self.pharos.lock().await;
+
+		ph.close().await
+	}
+
+
+	/// Observe with a function that turns every event into any number of events of another type.
+	/// See [Pharos::observe_expand].
+	//
+	pub async fn observe_expand<Out, F>( &self, options: ObserveConfig<Event>, expand: F ) -> Result< Events<Out>, PharErr >
+
+		where Out: Clone + 'static + Send                     ,
+		      F  : FnMut( &Event ) -> Vec<Out> + Send + 'static ,
+	{
+		let mut ph = self.pharos.lock().await;
+
+		ph.observe_expand( options, expand ).await
+	}
+
+
+	/// Observe only the events for which `project` returns `Some`, receiving the projected value.
+	/// See [Pharos::observe_variant].
+	//
+	pub async fn observe_variant<Out, F>( &self, options: ObserveConfig<Event>, project: F ) -> Result< Events<Out>, PharErr >
+
+		where Out: Clone + 'static + Send                        ,
+		      F  : FnMut( &Event ) -> Option<Out> + Send + 'static ,
+	{
+		let mut ph = self.pharos.lock().await;
+
+		ph.observe_variant( options, project ).await
+	}
+
+
+	/// Observe with a filter that also produces a value, which is delivered with the event.
+	/// See [Pharos::observe_with_output].
+	//
+	pub async fn observe_with_output<Out, F>( &self, options: ObserveConfig<Event>, filter: F ) -> Result< Events<(Event, Out)>, PharErr >
+
+		where Out: Clone + 'static + Send                        ,
+		      F  : FnMut( &Event ) -> Option<Out> + Send + 'static ,
+	{
+		let mut ph = self.pharos.lock().await;
+
+		ph.observe_with_output( options, filter ).await
+	}
+
+
+	/// Observe events tagged with a sequence number. See [Pharos::observe_sequenced].
 	//
-	pub async fn observe_shared( &self, options: ObserveConfig<Event> ) -> Result<Events<Event>, <Self as Observable<Event>>::Error >
+	pub async fn observe_sequenced( &self, options: ObserveConfig<Event> ) -> Result< Events<Seq<Event>>, PharErr >
 	{
 		let mut ph = self.pharos.lock().await;
 
-		ph.observe( options ).await
+		ph.observe_sequenced( options ).await
+	}
+
+
+	/// Observe with an in-band signal for lost events. See [Pharos::observe_lossy].
+	//
+	pub async fn observe_lossy( &self, options: ObserveConfig<Event> ) -> Result< Events<Lossy<Event>>, PharErr >
+	{
+		let mut ph = self.pharos.lock().await;
+
+		ph.observe_lossy( options ).await
+	}
+
+
+	/// Start Observing this Pharos object.
+	//
+	pub async fn observe_shared( &self, mut options: ObserveConfig<Event> ) -> Result<Events<Event>, <Self as Observable<Event>>::Error >
+	{
+		// Run the backfill without holding the lock, so other tasks can still notify. Live events
+		// sent in the mean time are buffered in the channel, behind the backfill.
+		//
+		let backfill = options.take_backfill();
+
+		let mut events =
+		{
+			let mut ph = self.pharos.lock().await;
+
+			ph.observe( options ).await?
+		};
+
+		if let Some( backfill ) = backfill
+		{
+			events.prepend( backfill.run().await );
+		}
+
+		Ok( events )
+	}
+
+
+	/// Observe, receiving the events in `history` before any live event. See [Pharos::observe_prefixed].
+	//
+	pub async fn observe_prefixed( &self, options: ObserveConfig<Event>, history: Vec<Event> ) -> Result< Events<Event>, PharErr >
+	{
+		let mut events = self.observe_shared( options ).await?;
+
+		events.prepend( history );
+
+		Ok( events )
+	}
+
+
+	/// Start observing this Pharos object with the stricter [SyncEvent] bound on the event type.
+	///
+	/// This behaves exactly like [observe_shared](SharedPharos::observe_shared), but it will only compile
+	/// when `Event` is also `Sync`. Use it when the events are going to be shared by reference between
+	/// threads (eg. `Arc<Event>`), so a missing `Sync` impl is reported here rather than somewhere
+	/// in your consumer code.
+	//
+	pub async fn observe_sync_events( &self, options: ObserveConfig<Event> ) -> Result<Events<Event>, PharErr >
+
+		where Event: SyncEvent
+	{
+		self.observe_shared( options ).await
+	}
+}
+
+
+impl<Event> From< Pharos<Event> > for SharedPharos<Event>
+
+	where Event: 'static + Clone + Send
+
+{
+	fn from( pharos: Pharos<Event> ) -> Self
+	{
+		Self::new( pharos )
 	}
 }
 
@@ -55,6 +324,23 @@ impl<Event> Default for SharedPharos<Event>
 }
 
 
+impl<T, E> SharedPharos< Result<T, E> >
+
+	where T: Clone + 'static + Send ,
+	      E: Clone + 'static + Send ,
+{
+	/// Observe `Ok` values and `Err` values on separate streams. See [Pharos::observe_results].
+	//
+	pub async fn observe_results( &self, options: ObserveConfig< Result<T, E> > ) -> Result< (Events<T>, Events<E>), PharErr >
+	{
+		let mut ph = self.pharos.lock().await;
+
+		ph.observe_results( options ).await
+	}
+}
+
+
+
 impl<Event> Observable<Event> for SharedPharos<Event>
 
 	where Event: 'static + Clone + Send
@@ -0,0 +1,40 @@
+use crate   :: { Events, PharErr };
+use futures :: { task::{ Spawn, SpawnExt }, future::RemoteHandle, StreamExt };
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Handle every event on a task spawned on `spawner`, calling `handler` for each. Use this when handling
+	/// events is CPU heavy, so it doesn't hold up the task that notifies. Pharos doesn't depend on an async runtime,
+	/// so you pass anything that implements [Spawn].
+	///
+	/// The returned handle resolves once the stream has ended and all events have been handled. Dropping it
+	/// cancels the task: it stops at the next event and drops this stream, so the observable stops sending to it.
+	/// Call [`forget`](RemoteHandle::forget) on the handle to let the task run in the background instead.
+	///
+	/// ```
+	/// use pharos::*;
+	///
+	/// # async fn task( events: Events<usize> ) -> Result<(), PharErr> {
+	/// let exec   = async_executors::AsyncStd;
+	/// let handle = events.spawn_handler( &exec, |n| { let _ = n.pow( 2 ); } )?;
+	///
+	/// // Waits for the observable to end the stream.
+	/// //
+	/// handle.await;
+	/// # Ok(()) }
+	/// ```
+	//
+	pub fn spawn_handler<F>( self, spawner: &dyn Spawn, mut handler: F ) -> Result< RemoteHandle<()>, PharErr >
+
+		where F: FnMut( Event ) + Send + 'static
+	{
+		let task = self.for_each( move |evt|
+		{
+			handler( evt );
+			futures::future::ready(())
+		});
+
+		Ok( spawner.spawn_with_handle( task )? )
+	}
+}
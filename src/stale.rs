@@ -0,0 +1,68 @@
+use crate :: { import::*, Events, events::Adapter };
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Skip events that are older than `max_age` when they are read, eg. for a real time consumer that was
+	/// blocked for a while: instead of working through the backlog, it catches up with the live events.
+	///
+	/// Pharos does not read the clock, so it works with any runtime and on every platform. `age` tells how old
+	/// an event is, typically from a timestamp the event carries. It is called when the consumer polls, so the
+	/// age is measured at the moment the event would be delivered. Events that are exactly `max_age` old are
+	/// still delivered. The order of events is preserved.
+	///
+	/// ```
+	/// use pharos::*;
+	/// use std::time::{ Duration, Instant };
+	///
+	/// #[ derive( Clone ) ]
+	/// //
+	/// struct Tick { at: Instant }
+	///
+	/// # fn task( events: Events<Tick> ) {
+	/// let live = events.drop_stale( Duration::from_millis( 100 ), |tick| tick.at.elapsed() );
+	/// # }
+	/// ```
+	//
+	pub fn drop_stale<F>( self, max_age: Duration, age: F ) -> Events<Event>
+
+		where F: FnMut( &Event ) -> Duration + Send + 'static ,
+	{
+		Events::from_adapter( self, |events| Stale{ events, max_age, age } )
+	}
+}
+
+
+
+struct Stale<Event, F> where Event: Clone + 'static + Send
+{
+	events : Events<Event> ,
+	max_age: Duration      ,
+	age    : F             ,
+}
+
+
+
+impl<Event, F> Adapter<Event> for Stale<Event, F>
+
+	where Event: Clone + 'static + Send                     ,
+	      F    : FnMut( &Event ) -> Duration + Send + 'static ,
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Event> >
+	{
+		loop
+		{
+			match ready!( Pin::new( &mut self.events ).poll_next( cx ) )
+			{
+				Some( evt ) if (self.age)( &evt ) > self.max_age => continue                  ,
+				evt                                              => return Poll::Ready( evt ),
+			}
+		}
+	}
+
+
+	fn close( &mut self )
+	{
+		self.events.close();
+	}
+}
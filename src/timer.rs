@@ -0,0 +1,43 @@
+use crate :: { import::* };
+
+
+/// A source of timers. Pharos does not depend on any async runtime, so adapters that need to wait take
+/// a `Timer` as a parameter.
+///
+/// It is implemented for every function or closure that takes a [Duration] and returns a future, so
+/// you can just pass the sleep function of your runtime or a timer library:
+///
+/// ```ignore
+/// // async-std
+/// //
+/// events.aggregate( Duration::from_secs(1), async_std::task::sleep );
+///
+/// // futures-timer
+/// //
+/// events.aggregate( Duration::from_secs(1), futures_timer::Delay::new );
+/// ```
+//
+pub trait Timer: Send + 'static
+{
+	/// The future returned by [sleep](Timer::sleep).
+	//
+	type Sleep: Future< Output = () > + Send + 'static;
+
+	/// Create a future that resolves after `dur` has elapsed.
+	//
+	fn sleep( &self, dur: Duration ) -> Self::Sleep;
+}
+
+
+impl<F, Fut> Timer for F
+
+	where F  : Fn( Duration ) -> Fut + Send + 'static ,
+	      Fut: Future< Output = () > + Send + 'static ,
+{
+	type Sleep = Fut;
+
+	fn sleep( &self, dur: Duration ) -> Self::Sleep
+	{
+		self( dur )
+	}
+}
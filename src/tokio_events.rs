@@ -0,0 +1,77 @@
+use crate :: { import::*, Events };
+
+
+/// A wrapper around [Events] for code that expects a [`tokio_stream::Stream`].
+///
+/// `tokio_stream::Stream` is the same trait as the futures `Stream` that [Events] already implements, so this
+/// is just a newtype. It exists so that code living in the tokio ecosystem can name a type that is
+/// bound on the tokio-stream re-export, without having to write the newtype themselves.
+///
+/// You can still [close](TokioEvents::close) the stream or get the [Events] back with
+/// [into_inner](TokioEvents::into_inner).
+///
+/// Requires the `tokio-stream` feature.
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "tokio-stream" )) ) ]
+//
+#[ derive( Debug ) ]
+//
+pub struct TokioEvents<Event> where Event: Clone + 'static + Send
+{
+	events: Events<Event>,
+}
+
+
+impl<Event> TokioEvents<Event> where Event: Clone + 'static + Send
+{
+	/// Disconnect from the observable object. See [Events::close].
+	//
+	pub fn close( &mut self )
+	{
+		self.events.close();
+	}
+
+
+	/// Get back the wrapped [Events].
+	//
+	pub fn into_inner( self ) -> Events<Event>
+	{
+		self.events
+	}
+}
+
+
+impl<Event> Events<Event> where Event: Clone + 'static + Send
+{
+	/// Wrap this stream in a [TokioEvents] for interop with the tokio-stream ecosystem.
+	///
+	/// Requires the `tokio-stream` feature.
+	//
+	#[ cfg_attr( nightly, doc(cfg( feature = "tokio-stream" )) ) ]
+	//
+	pub fn into_tokio_stream( self ) -> TokioEvents<Event>
+	{
+		TokioEvents{ events: self }
+	}
+}
+
+
+impl<Event> From<Events<Event>> for TokioEvents<Event> where Event: Clone + 'static + Send
+{
+	fn from( events: Events<Event> ) -> Self
+	{
+		events.into_tokio_stream()
+	}
+}
+
+
+
+impl<Event> ::tokio_stream::Stream for TokioEvents<Event> where Event: Clone + 'static + Send
+{
+	type Item = Event;
+
+	fn poll_next( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll< Option<Self::Item> >
+	{
+		Pin::new( &mut self.events ).poll_next( cx )
+	}
+}
@@ -0,0 +1,170 @@
+use crate :: { import::*, Events, events::Adapter };
+use std   :: { task::{ Wake, Waker } };
+
+
+/// Ends an observer after some time. See [ObserveConfig::ttl](crate::ObserveConfig::ttl).
+//
+pub(crate) struct Ttl( Box< dyn FnOnce() -> BoxFuture< 'static, () > + Send > );
+
+
+impl Ttl
+{
+	pub(crate) fn new( sleep: impl FnOnce() -> BoxFuture< 'static, () > + Send + 'static ) -> Self
+	{
+		Self( Box::new( sleep ) )
+	}
+
+
+	/// Start the timer.
+	//
+	pub(crate) fn start( self ) -> Arc<Deadline>
+	{
+		let deadline = Arc::new( Deadline
+		{
+			sleep: SyncMutex::new( Some( (self.0)() ) ) ,
+			waker: Arc::new( Consumer::default() )      ,
+		});
+
+		// Poll once, so timers that only start when they are polled count from now.
+		//
+		deadline.elapsed();
+		deadline
+	}
+}
+
+
+impl fmt::Debug for Ttl
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		write!( f, "pharos::Ttl" )
+	}
+}
+
+
+
+/// The running timer of a ttl. It's shared by the sender and the events of an observer, so the observable
+/// notices that it elapsed even if the consumer doesn't poll.
+//
+pub(crate) struct Deadline
+{
+	// None once it has elapsed.
+	//
+	sleep: SyncMutex< Option< BoxFuture< 'static, () > > > ,
+	waker: Arc<Consumer>                                   ,
+}
+
+
+impl Deadline
+{
+	/// Whether the ttl has elapsed. Whoever checks, the timer wakes the consumer.
+	//
+	pub(crate) fn elapsed( &self ) -> bool
+	{
+		let mut sleep = self.sleep.lock().expect( "lock deadline" );
+
+		let elapsed = match sleep.as_mut()
+		{
+			Some( s ) =>
+			{
+				let waker = Waker::from( self.waker.clone() );
+
+				s.as_mut().poll( &mut Context::from_waker( &waker ) ).is_ready()
+			}
+
+			None => true,
+		};
+
+		if elapsed { *sleep = None; }
+
+		elapsed
+	}
+
+
+	/// Wake `waker` when the ttl elapses.
+	//
+	pub(crate) fn register( &self, waker: &Waker )
+	{
+		self.waker.0.register( waker );
+	}
+}
+
+
+impl fmt::Debug for Deadline
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		write!( f, "pharos::Deadline" )
+	}
+}
+
+
+
+// The timer is polled by the sender as well, which must not take the place of the waker of the
+// consumer. So it always gets this one, which wakes the consumer.
+//
+#[ derive( Default ) ]
+//
+struct Consumer( AtomicWaker );
+
+
+impl Wake for Consumer
+{
+	fn wake( self: Arc<Self> )
+	{
+		self.0.wake();
+	}
+
+
+	fn wake_by_ref( self: &Arc<Self> )
+	{
+		self.0.wake();
+	}
+}
+
+
+
+/// Closes the events it wraps once the timer of the ttl has elapsed.
+//
+pub(crate) struct Expire<Event> where Event: Clone + 'static + Send
+{
+	events  : Events<Event>           ,
+	deadline: Option< Arc<Deadline> > ,
+}
+
+
+
+impl<Event> Expire<Event> where Event: Clone + 'static + Send
+{
+	pub(crate) fn new( events: Events<Event>, deadline: Arc<Deadline> ) -> Self
+	{
+		Self{ events, deadline: Some( deadline ) }
+	}
+}
+
+
+
+impl<Event> Adapter<Event> for Expire<Event> where Event: Clone + 'static + Send
+{
+	fn poll_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option<Event> >
+	{
+		if let Some( deadline ) = &self.deadline
+		{
+			deadline.register( cx.waker() );
+
+			if deadline.elapsed()
+			{
+				self.close();
+			}
+		}
+
+		Pin::new( &mut self.events ).poll_next( cx )
+	}
+
+
+	fn close( &mut self )
+	{
+		self.deadline = None;
+		self.events.close();
+	}
+}
@@ -0,0 +1,95 @@
+use crate :: { import::*, Pharos, PharErr, Events, ObserveConfig, events::{ Tx, Relay } };
+
+
+impl<Event> Pharos<Event> where Event: Clone + 'static + Send
+{
+	/// Observe only the events for which `project` returns `Some`, receiving the projected value. This lets you
+	/// subscribe to a few variants of an enum and get them as a narrower type, so you don't have to match
+	/// on variants you never receive.
+	///
+	/// Events are projected before they are sent, so only the observer's type goes through the channel. The filter
+	/// of `options` still applies and runs before `project`. Like for [observe_expand](Pharos::observe_expand),
+	/// the options for priority, backfill and reconnecting are ignored.
+	///
+	/// ```
+	/// use pharos::*;
+	///
+	/// #[ derive( Clone ) ] enum Conn  { Open, Data( Vec<u8> ), Error( String ), Closed }
+	/// #[ derive( Clone ) ] enum State { Open, Closed }
+	///
+	/// # async fn task( mut pharos: Pharos<Conn> ) -> Result<(), PharErr> {
+	/// let state = pharos.observe_variant( ObserveConfig::default(), |evt| match evt
+	/// {
+	///    Conn::Open   => Some( State::Open   ),
+	///    Conn::Closed => Some( State::Closed ),
+	///    _            => None                 ,
+	///
+	/// }).await?;
+	/// # Ok(()) }
+	/// ```
+	//
+	pub async fn observe_variant<Out, F>( &mut self, options: ObserveConfig<Event>, project: F ) -> Result< Events<Out>, PharErr >
+
+		where Out: Clone + 'static + Send                        ,
+		      F  : FnMut( &Event ) -> Option<Out> + Send + 'static ,
+	{
+		self.check( &options )?;
+
+		let (events, sender) = Events::with_relay( options, |tx, _| Project{ tx, project } );
+
+		self.register( sender );
+
+		Ok( events )
+	}
+}
+
+
+
+struct Project<Out, F> where Out: Clone + 'static + Send
+{
+	tx     : Tx<Out> ,
+	project: F       ,
+}
+
+
+
+// At most one item is sent per event, so readiness of the channel is all we need.
+//
+impl<Event, Out, F> Relay<Event> for Project<Out, F>
+
+	where Out: Clone + 'static + Send                        ,
+	      F  : FnMut( &Event ) -> Option<Out> + Send + 'static ,
+{
+	fn is_closed( &self ) -> bool
+	{
+		self.tx.is_closed()
+	}
+
+
+	fn poll_ready( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		Pin::new( &mut self.tx ).poll_ready( cx )
+	}
+
+
+	fn start_send( &mut self, evt: Event ) -> Result<bool, PharErr>
+	{
+		match (self.project)( &evt )
+		{
+			Some( out ) => self.tx.deliver( out ),
+			None        => Ok( false )           ,
+		}
+	}
+
+
+	fn poll_flush( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		Pin::new( &mut self.tx ).poll_flush( cx )
+	}
+
+
+	fn poll_close( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		Pin::new( &mut self.tx ).poll_close( cx )
+	}
+}
@@ -0,0 +1,89 @@
+use crate :: { import::*, Pharos, PharErr, Events, ObserveConfig, events::{ Tx, Relay } };
+
+
+impl<Event> Pharos<Event> where Event: Clone + 'static + Send
+{
+	/// Observe with a filter that also produces a value. Events for which `filter` returns `Some` are delivered
+	/// together with that value, the others are skipped. Use this when deciding whether you want an event
+	/// already computes something you need when handling it, so it is computed exactly once.
+	///
+	/// If you only need the value, use [observe_variant](Pharos::observe_variant) instead. The filter of `options`
+	/// still applies and runs before `filter`. Like for [observe_expand](Pharos::observe_expand), the options for
+	/// priority, backfill and reconnecting are ignored.
+	///
+	/// ```
+	/// use pharos::*;
+	///
+	/// # async fn task( mut pharos: Pharos<String> ) -> Result<(), PharErr> {
+	/// // Only lines that parse as a number, with the number.
+	/// //
+	/// let numbers = pharos.observe_with_output( ObserveConfig::default(), |line| line.parse::<u64>().ok() ).await?;
+	/// # Ok(()) }
+	/// ```
+	//
+	pub async fn observe_with_output<Out, F>( &mut self, options: ObserveConfig<Event>, filter: F ) -> Result< Events<(Event, Out)>, PharErr >
+
+		where Out: Clone + 'static + Send                        ,
+		      F  : FnMut( &Event ) -> Option<Out> + Send + 'static ,
+	{
+		self.check( &options )?;
+
+		let (events, sender) = Events::with_relay( options, |tx, _| Attach{ tx, filter } );
+
+		self.register( sender );
+
+		Ok( events )
+	}
+}
+
+
+
+struct Attach<Event, Out, F> where Event: Clone + 'static + Send, Out: Clone + 'static + Send
+{
+	tx    : Tx<(Event, Out)> ,
+	filter: F                ,
+}
+
+
+
+// At most one item is sent per event, so readiness of the channel is all we need.
+//
+impl<Event, Out, F> Relay<Event> for Attach<Event, Out, F>
+
+	where Event: Clone + 'static + Send                        ,
+	      Out  : Clone + 'static + Send                        ,
+	      F    : FnMut( &Event ) -> Option<Out> + Send + 'static ,
+{
+	fn is_closed( &self ) -> bool
+	{
+		self.tx.is_closed()
+	}
+
+
+	fn poll_ready( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		Pin::new( &mut self.tx ).poll_ready( cx )
+	}
+
+
+	fn start_send( &mut self, evt: Event ) -> Result<bool, PharErr>
+	{
+		match (self.filter)( &evt )
+		{
+			Some( out ) => self.tx.deliver(( evt, out )),
+			None        => Ok( false )                  ,
+		}
+	}
+
+
+	fn poll_flush( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		Pin::new( &mut self.tx ).poll_flush( cx )
+	}
+
+
+	fn poll_close( &mut self, cx: &mut Context<'_> ) -> Poll<Result<(), PharErr>>
+	{
+		Pin::new( &mut self.tx ).poll_close( cx )
+	}
+}
@@ -0,0 +1,102 @@
+// Tested:
+//
+// ✔ statistics of a window.
+// ✔ the last incomplete window is emitted when the stream ends.
+// ✔ empty windows emit nothing, or a zero count aggregate if asked.
+// ✔ close is forwarded to the source.
+// ✔ windows close in time when events are always ready.
+// ✔ a timer that is ready right away doesn't keep the events from being read.
+//
+mod common;
+
+use common::import::*;
+use std::time::Duration;
+
+
+#[ async_std::test ]
+//
+async fn aggregate()
+{
+	let mut ph     = Pharos::<u32>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).aggregate( Duration::from_millis( 10 ), async_std::task::sleep );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 6 ).await.expect( "send" );
+
+	let agg = events.next().await.unwrap();
+
+		assert_eq!( Aggregate{ count: 3, min: 1.0, max: 6.0, sum: 9.0, mean: 3.0 }, agg );
+
+	ph.send( 5 ).await.expect( "send" );
+	drop( ph );
+
+	let agg = events.next().await.unwrap();
+
+		assert_eq!( Aggregate{ count: 1, min: 5.0, max: 5.0, sum: 5.0, mean: 5.0 }, agg );
+		assert_eq!( None, events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn emit_empty()
+{
+	let mut ph     = Pharos::<u32>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" )
+
+		.aggregate_by( Duration::from_millis( 5 ), async_std::task::sleep, |e| f64::from( e ) * 2.0, true )
+	;
+
+	assert_eq!( Aggregate::default(), events.next().await.unwrap() );
+
+	ph.send( 2 ).await.expect( "send" );
+	events.close();
+
+	assert_eq!( 4.0 , events.next().await.unwrap().sum );
+	assert_eq!( None, events.next().await              );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn sustained()
+{
+	// All events are ready right away, handling them takes 100ms.
+	//
+	let slow = |evt: u32|
+	{
+		thread::sleep( Duration::from_millis( 1 ) );
+		evt as f64
+	};
+
+	let events  = (0..100).collect::<Events<u32>>().aggregate_by( Duration::from_millis( 20 ), async_std::task::sleep, slow, false );
+	let windows = events.collect::<Vec<_>>().await;
+
+	assert!( windows.len() >= 3 );
+	assert_eq!( 100, windows.iter().map( |w| w.count ).sum::<usize>() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn ready_timer()
+{
+	let mut ph  = Pharos::<usize>::default();
+	let events  = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let windows = events.aggregate_by( Duration::ZERO, |_| futures::future::ready(()), |n| n as f64, false );
+
+	for i in 0..3
+	{
+		ph.send( i ).await.expect( "send" );
+	}
+
+	drop( ph );
+
+	let windows = async_std::future::timeout( Duration::from_secs( 5 ), windows.collect::<Vec<_>>() ).await.expect( "no hang" );
+
+	assert_eq!( 3, windows.iter().map( |w| w.count ).sum::<usize>() );
+}
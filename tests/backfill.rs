@@ -0,0 +1,55 @@
+// Tested:
+//
+// ✔ backfilled events are delivered before live events.
+// ✔ backfill doesn't count towards the capacity of a bounded channel.
+// ✔ SharedPharos doesn't hold the lock during the backfill and live events sent during the backfill come after it.
+//
+mod common;
+
+use common::{ *, import::* };
+
+
+#[ async_std::test ]
+//
+async fn backfill()
+{
+	let mut isis = Goddess::new();
+	let opts     = ObserveConfig::from( Channel::Bounded( 1 ) ).backfill( || async { vec![ IsisEvent::Dock, IsisEvent::Dock ] } );
+
+	let mut events = isis.observe( opts ).await.expect( "observe" );
+
+	isis.sail().await;
+	drop( isis );
+
+	assert_eq!( IsisEvent::Dock, events.next().await.unwrap() );
+	assert_eq!( IsisEvent::Dock, events.next().await.unwrap() );
+	assert_eq!( IsisEvent::Sail, events.next().await.unwrap() );
+	assert_eq!( None           , events.next().await          );
+}
+
+
+
+// The backfill notifies itself. If the lock was held, this would deadlock.
+//
+#[ async_std::test ]
+//
+async fn backfill_shared()
+{
+	let shared = SharedPharos::<IsisEvent>::default();
+	let live   = shared.clone();
+
+	let opts = ObserveConfig::default().backfill( move || async move
+	{
+		live.notify( IsisEvent::Sail ).await.expect( "notify" );
+
+		vec![ IsisEvent::Dock ]
+	});
+
+	let mut events = shared.observe_shared( opts ).await.expect( "observe" );
+
+	drop( shared );
+
+	assert_eq!( IsisEvent::Dock, events.next().await.unwrap() );
+	assert_eq!( IsisEvent::Sail, events.next().await.unwrap() );
+	assert_eq!( None           , events.next().await          );
+}
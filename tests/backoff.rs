@@ -0,0 +1,79 @@
+// Tested:
+//
+// ✔ the first event of a key is delivered right away, repeats are summarized when the window closes.
+// ✔ keys are suppressed independently.
+// ✔ the window doubles while repeats keep coming, up to the maximum.
+// ✔ a key that was quiet for a whole window is delivered right away again.
+// ✔ suppressed events are summarized when the stream ends.
+//
+mod common;
+
+use common::import::*;
+use std::time::{ Duration, Instant };
+
+
+fn ms( n: u64 ) -> Duration { Duration::from_millis( n ) }
+
+
+
+#[ async_std::test ]
+//
+async fn backoff()
+{
+	let mut ph     = Pharos::<( char, usize )>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" )
+
+		.backoff_dedup( |e| e.0, ms( 30 ), ms( 30 ), async_std::task::sleep );
+
+	for evt in [ ('a', 1), ('a', 2), ('b', 1), ('a', 3) ]
+	{
+		ph.send( evt ).await.expect( "send" );
+	}
+
+	assert_eq!( Some( Backoff::First( ('a', 1) ) ), events.next().await );
+	assert_eq!( Some( Backoff::First( ('b', 1) ) ), events.next().await );
+
+	let summary = events.next().await;
+
+	assert_eq!( Some( Backoff::Repeated{ event: ('a', 3), count: 2 } ), summary );
+
+	// Let the windows pass without events.
+	//
+	async_std::task::sleep( ms( 100 ) ).await;
+
+	ph.send( ('a', 4) ).await.expect( "send" );
+	assert_eq!( Some( Backoff::First( ('a', 4) ) ), events.next().await );
+
+	ph.send( ('a', 5) ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( Some( Backoff::Repeated{ event: ('a', 5), count: 1 } ), events.next().await );
+	assert_eq!( None, events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn escalate()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" )
+
+		.backoff_dedup( |_| (), ms( 20 ), ms( 40 ), async_std::task::sleep );
+
+	ph.send( 0 ).await.expect( "send" );
+	assert_eq!( Some( Backoff::First( 0 ) ), events.next().await );
+
+	// Windows of 20, 40 and 40ms.
+	//
+	for (i, min) in [ ( 1, 0 ), ( 2, 30 ), ( 3, 30 ) ]
+	{
+		ph.send( i ).await.expect( "send" );
+
+		let start = Instant::now();
+
+		assert_eq!( Some( Backoff::Repeated{ event: i, count: 1 } ), events.next().await );
+		assert!( start.elapsed() >= ms( min ) );
+	}
+}
@@ -8,6 +8,7 @@
 // ✔ Basic filter usage, only one event type should be returned.
 // ✔ A filter that always returns true should get all events.
 // ✔ A filter that always returns false should not get any events.
+// ✔ observe_bounded with and without filter.
 //
 mod common;
 
@@ -315,3 +316,27 @@ async fn filter_move()
 }
 
 
+
+// The shorthand takes an optional filter.
+//
+#[ async_std::test ]
+//
+async fn observe_bounded()
+{
+	let mut isis = Goddess::new();
+
+	let mut docks = isis.observe_bounded( 5, Filter::Pointer( |e| *e == IsisEvent::Dock ) ).await.expect( "observe" );
+	let mut all   = isis.observe_bounded( 5, None ).await.expect( "observe" );
+
+	isis.sail().await;
+	isis.dock().await;
+
+	drop( isis );
+
+	assert_eq!( IsisEvent::Dock, docks.next().await.unwrap() );
+	assert_eq!( None           , docks.next().await          );
+
+	assert_eq!( IsisEvent::Sail, all.next().await.unwrap() );
+	assert_eq!( IsisEvent::Dock, all.next().await.unwrap() );
+	assert_eq!( None           , all.next().await          );
+}
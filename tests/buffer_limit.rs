@@ -0,0 +1,215 @@
+// Tested:
+//
+// ✔ buffered counts events until they are consumed, across observers and for relays.
+// ✔ BufferPolicy::Wait: notifying waits until an observer consumes.
+// ✔ BufferPolicy::DropNewest: events are discarded and counted as dropped.
+// ✔ BufferPolicy::DropOldest: the oldest events of the slowest observer are discarded and counted as dropped.
+// ✔ BufferPolicy::DropOldest: discarded events stay discarded when the observer switches channels.
+// ✔ BufferPolicy::Error: notifying returns ErrorKind::BufferFull.
+// ✔ observers that dropped their events don't count.
+// ✔ removing the limit.
+// ✔ pending_len_per_observer, also on SharedPharos.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn buffered()
+{
+	let mut ph    = Pharos::<usize>::default();
+	let mut a     = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let mut split = ph.observe_expand( ObserveConfig::default(), |n| vec![ *n; 2 ] ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	assert_eq!( 3, ph.buffered() );
+
+	a    .next().await;
+	split.next().await;
+
+	assert_eq!( 1, ph.buffered() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn wait()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.set_buffer_limit( Some( 2 ), BufferPolicy::Wait );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+
+	{
+		let mut send = ph.send( 3 );
+
+		assert!( futures::poll!( &mut send ).is_pending() );
+		assert_eq!( Some( 1 ), events.next().await );
+		assert!( futures::poll!( &mut send ).is_ready() );
+	}
+
+	drop( ph );
+
+	assert_eq!( vec![ 2, 3 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn drop_policy()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.set_buffer_limit( Some( 1 ), BufferPolicy::DropNewest );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+
+	assert_eq!( Some( 1 ), events.next().await );
+
+	ph.send( 3 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( 1, events.dropped() );
+	assert_eq!( vec![ 3 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn drop_oldest()
+{
+	let mut ph   = Pharos::<usize>::default();
+	let mut fast = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let     slow = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.set_buffer_limit( Some( 2 ), BufferPolicy::DropOldest );
+
+	ph.send( 1 ).await.expect( "send" );
+	assert_eq!( Some( 1 ), fast.next().await );
+
+	ph.send( 2 ).await.expect( "send" );
+	assert_eq!( Some( 2 ), fast.next().await );
+
+	ph.send( 3 ).await.expect( "send" );
+	assert_eq!( Some( 3 ), fast.next().await );
+
+	assert_eq!( 2, ph.buffered() );
+	drop( ph );
+
+	assert_eq!( 0, fast.dropped() );
+	assert_eq!( 1, slow.dropped() );
+	assert_eq!( vec![ 2, 3 ], slow.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn drop_oldest_set_channel()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default().reconnectable() ).await.expect( "observe" );
+
+	ph.set_buffer_limit( Some( 2 ), BufferPolicy::DropOldest );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+
+	events.set_channel( Channel::Unbounded ).expect( "set_channel" );
+
+	ph.send( 4 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( 1, events.dropped() );
+	assert_eq!( vec![ 2, 3, 4 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn error()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.set_buffer_limit( Some( 1 ), BufferPolicy::Error );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	assert_eq!( ErrorKind::BufferFull, ph.send( 2 ).await.unwrap_err().kind() );
+
+	events.next().await;
+
+	ph.send( 3 ).await.expect( "send" );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn disconnected()
+{
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.set_buffer_limit( Some( 1 ), BufferPolicy::Error );
+
+	ph.send( 1 ).await.expect( "send" );
+	drop( events );
+
+	assert_eq!( 0, ph.buffered() );
+	ph.send( 2 ).await.expect( "send" );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn unlimited()
+{
+	let mut ph  = Pharos::<usize>::default();
+	let _events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.set_buffer_limit( Some( 1 ), BufferPolicy::Error );
+	ph.send( 1 ).await.expect( "send" );
+
+	ph.set_buffer_limit( None, BufferPolicy::Error );
+	ph.send( 2 ).await.expect( "send" );
+
+	assert_eq!( 2, ph.buffered() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn pending_len_per_observer()
+{
+	let mut ph = Pharos::<usize>::default();
+	let mut a  = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let b      = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	a.next().await;
+
+	assert_eq!( vec![ ( a.id(), 1 ), ( b.id(), 2 ) ], ph.pending_len_per_observer() );
+
+
+	let shared = SharedPharos::from( ph );
+
+	assert_eq!( vec![ ( a.id(), 1 ), ( b.id(), 2 ) ], shared.pending_len_per_observer().await );
+}
@@ -0,0 +1,55 @@
+// Tested:
+//
+// ✔ the report has the high water mark, the number of events sent and consumed for every observer.
+// ✔ starting to profile resets the counters and starts the high water mark from the current depth.
+// ✔ rates are computed over the given duration.
+// ✔ SharedPharos::capacity_report.
+//
+mod common;
+
+use common::import::*;
+use std::time::Duration;
+
+
+#[ async_std::test ]
+//
+async fn report()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	for i in 0..5 { ph.send( i ).await.expect( "send" ); }
+	for _ in 0..4 { events.next().await; }
+
+	let report = ph.capacity_report();
+
+	assert_eq!( vec![ ObserverCapacity{ id: events.id(), buffered: 1, high_water: 5, sent: 5, consumed: 4 } ], report.observers );
+
+	ph.start_capacity_profiling();
+
+	ph.send( 5 ).await.expect( "send" );
+	ph.send( 6 ).await.expect( "send" );
+	events.next().await;
+
+	let obs = ph.capacity_report().observers.remove( 0 );
+
+	assert_eq!( ObserverCapacity{ id: events.id(), buffered: 2, high_water: 3, sent: 2, consumed: 1 }, obs );
+
+	assert_eq!( 1.0, obs.send_rate   ( Duration::from_secs( 2 ) ) );
+	assert_eq!( 0.5, obs.consume_rate( Duration::from_secs( 2 ) ) );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph     = SharedPharos::<usize>::default();
+	let events = ph.observe_shared( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.start_capacity_profiling().await;
+	ph.notify( 1 ).await.expect( "notify" );
+
+	assert_eq!( vec![ ObserverCapacity{ id: events.id(), buffered: 1, high_water: 1, sent: 1, consumed: 0 } ], ph.capacity_report().await.observers );
+}
@@ -0,0 +1,66 @@
+// Tested:
+//
+// ✔ a single observer receives the original event.
+// ✔ with several observers only the last one receiving the event gets the original.
+// ✔ observers that filter the event out don't cause a clone.
+//
+mod common;
+
+use common::import::*;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+
+
+// Counts how many times it was cloned.
+//
+#[ derive( Debug ) ]
+//
+struct Big
+{
+	clones: Arc<AtomicUsize>,
+}
+
+
+impl Clone for Big
+{
+	fn clone( &self ) -> Self
+	{
+		self.clones.fetch_add( 1, Ordering::Relaxed );
+
+		Self{ clones: self.clones.clone() }
+	}
+}
+
+
+
+async fn clones_for( observers: usize, filtered: usize ) -> usize
+{
+	let mut ph   = Pharos::<Big>::default();
+	let clones   = Arc::new( AtomicUsize::new( 0 ) );
+	let mut keep  = Vec::new();
+
+	for _ in 0..observers
+	{
+		keep.push( ph.observe( ObserveConfig::default() ).await.expect( "observe" ) );
+	}
+
+	for _ in 0..filtered
+	{
+		keep.push( ph.observe( ObserveConfig::default().filter( |_| false ) ).await.expect( "observe" ) );
+	}
+
+	ph.send( Big{ clones: clones.clone() } ).await.expect( "send" );
+
+	clones.load( Ordering::Relaxed )
+}
+
+
+
+#[ async_std::test ]
+//
+async fn clones()
+{
+	assert_eq!( 0, clones_for( 1, 0 ).await );
+	assert_eq!( 2, clones_for( 3, 0 ).await );
+	assert_eq!( 0, clones_for( 1, 2 ).await );
+	assert_eq!( 1, clones_for( 2, 2 ).await );
+}
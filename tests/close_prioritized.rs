@@ -0,0 +1,44 @@
+// Tested:
+//
+// ✔ pending events are yielded highest key first, equal keys in order.
+// ✔ the observable stops sending right away.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ derive( Clone, Debug, PartialEq ) ]
+//
+enum Log
+{
+	Info ( usize ) ,
+	Error( usize ) ,
+}
+
+
+
+#[ async_std::test ]
+//
+async fn close_prioritized()
+{
+	let mut ph = Pharos::<Log>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.send( Log::Info ( 1 ) ).await.expect( "send" );
+	ph.send( Log::Error( 2 ) ).await.expect( "send" );
+	ph.send( Log::Info ( 3 ) ).await.expect( "send" );
+	ph.send( Log::Error( 4 ) ).await.expect( "send" );
+
+	let events = events.close_prioritized( |e| matches!( e, Log::Error(_) ) );
+
+	ph.send( Log::Error( 5 ) ).await.expect( "send" );
+
+	assert_eq!( 0, ph.num_observers() );
+
+	assert_eq!
+	(
+		vec![ Log::Error( 2 ), Log::Error( 4 ), Log::Info( 1 ), Log::Info( 3 ) ],
+		events.collect::<Vec<_>>().await
+	);
+}
@@ -0,0 +1,75 @@
+// Tested:
+//
+// ✔ runs of equal events are delivered with their count when a different event arrives.
+// ✔ the last run is delivered when the stream ends.
+// ✔ a run is delivered when the flush timer fires, an equal event after that starts a new run.
+// ✔ the flush timer of a run counts from the start of the run, for timers that only start when polled.
+//
+mod common;
+
+use common::import::*;
+use std::time::{ Duration, Instant };
+
+
+#[ async_std::test ]
+//
+async fn compact()
+{
+	let mut ph = Pharos::<&'static str>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).compact( Duration::from_secs( 10 ), async_std::task::sleep );
+
+	for evt in [ "a", "a", "a", "b", "a", "c", "c" ]
+	{
+		ph.send( evt ).await.expect( "send" );
+	}
+
+	drop( ph );
+
+	assert_eq!( vec![ ( "a", 3 ), ( "b", 1 ), ( "a", 1 ), ( "c", 2 ) ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn flush()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).compact( Duration::from_millis( 10 ), async_std::task::sleep );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 1 ).await.expect( "send" );
+
+	assert_eq!( Some(( 1, 2 )), events.next().await );
+
+	ph.send( 1 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( Some(( 1, 1 )), events.next().await );
+	assert_eq!( None          , events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn flush_from_start()
+{
+	let flush      = Duration::from_millis( 200 );
+	let mut ph     = Pharos::<&'static str>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).compact( flush, async_std::task::sleep );
+
+	ph.send( "a" ).await.expect( "send" );
+	ph.send( "b" ).await.expect( "send" );
+
+	assert_eq!( Some(( "a", 1 )), events.next().await );
+
+	// The run of "b" started with the previous poll, so it's due by now.
+	//
+	async_std::task::sleep( flush + flush / 4 ).await;
+
+	let start = Instant::now();
+
+	assert_eq!( Some(( "b", 1 )), events.next().await );
+	assert!( start.elapsed() < flush / 2 );
+}
@@ -0,0 +1,81 @@
+// Tested:
+//
+// ✔ events are delivered in order, not before their delay has passed.
+// ✔ events still delayed when closing are delivered before the stream ends.
+// ✔ delays of events sent together run at the same time, they don't add up.
+//
+mod common;
+
+use common::import::*;
+use std::time::{ Duration, Instant };
+
+
+#[ async_std::test ]
+//
+async fn delay()
+{
+	let dur        = Duration::from_millis( 20 );
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).delay( dur, async_std::task::sleep );
+
+	let start = Instant::now();
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( Some( 1 ), events.next().await );
+
+		assert!( start.elapsed() >= dur );
+
+	assert_eq!( Some( 2 ), events.next().await );
+	assert_eq!( Some( 3 ), events.next().await );
+	assert_eq!( None     , events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn close()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).delay( Duration::from_millis( 10 ), async_std::task::sleep );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	// Make sure the event is taken out of the channel before closing.
+	//
+	assert!( poll_fn( |cx| Poll::Ready( Pin::new( &mut events ).poll_next( cx ).is_pending() ) ).await );
+
+	events.close();
+
+	ph.send( 2 ).await.expect( "send" );
+
+	assert_eq!( Some( 1 ), events.next().await );
+	assert_eq!( None     , events.next().await );
+	assert_eq!( 0        , ph.num_observers()  );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn concurrent()
+{
+	let dur        = Duration::from_millis( 50 );
+	let mut ph     = Pharos::<usize>::default();
+	let events     = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).delay( dur, async_std::task::sleep );
+	let start      = Instant::now();
+
+	for i in 0..5 { ph.send( i ).await.expect( "send" ); }
+	drop( ph );
+
+	assert_eq!( vec![ 0, 1, 2, 3, 4 ], events.collect::<Vec<_>>().await );
+
+	let elapsed = start.elapsed();
+
+	assert!( elapsed >= dur     );
+	assert!( elapsed <  dur * 2 );
+}
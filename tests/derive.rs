@@ -0,0 +1,52 @@
+// Tested:
+//
+// ✔ only changes of the derived value are emitted, the first one always.
+// ✔ close is forwarded to the source.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ derive( Clone, Debug, PartialEq ) ]
+//
+struct State
+{
+	user : &'static str,
+	count: usize       ,
+}
+
+
+
+#[ async_std::test ]
+//
+async fn derive()
+{
+	let mut ph = Pharos::<State>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).derive( |s| s.user );
+
+	ph.send( State{ user: "ann", count: 0 } ).await.expect( "send" );
+	ph.send( State{ user: "ann", count: 1 } ).await.expect( "send" );
+	ph.send( State{ user: "bob", count: 1 } ).await.expect( "send" );
+	ph.send( State{ user: "bob", count: 2 } ).await.expect( "send" );
+	ph.send( State{ user: "ann", count: 2 } ).await.expect( "send" );
+
+	drop( ph );
+
+	assert_eq!( vec![ "ann", "bob", "ann" ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn close()
+{
+	let mut ph     = Pharos::<State>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).derive( |s| s.count );
+
+	events.close();
+
+	assert_eq!( 0   , ph.num_observers()  );
+	assert_eq!( None, events.next().await );
+}
@@ -0,0 +1,93 @@
+// Tested:
+//
+// ✔ every event is expanded into it's items in order, the filter applies to the original event.
+// ✔ on a bounded channel, notifying waits until the observer made room for all items.
+// ✔ items that don't fit in the channel are dropped when the pharos is closed.
+// ✔ SharedPharos::observe_expand.
+//
+mod common;
+
+use common::import::*;
+
+
+fn digits( n: &usize ) -> Vec<usize>
+{
+	n.to_string().chars().map( |c| c.to_digit( 10 ).unwrap() as usize ).collect()
+}
+
+
+
+#[ async_std::test ]
+//
+async fn expand()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let opts       = ObserveConfig::default().filter( |n| *n > 9 );
+	let events     = ph.observe_expand( opts, digits ).await.expect( "observe" );
+
+	ph.send( 123 ).await.expect( "send" );
+	ph.send(   4 ).await.expect( "send" );
+	ph.send(  56 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2, 3, 5, 6 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn backpressure()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe_expand( Channel::Bounded( 1 ).into(), digits ).await.expect( "observe" );
+
+	{
+		let mut send = ph.send( 123 );
+
+		assert!( futures::poll!( &mut send ).is_pending() );
+		assert_eq!( Some( 1 ), events.next().await );
+
+		assert!( futures::poll!( &mut send ).is_pending() );
+		assert_eq!( Some( 2 ), events.next().await );
+
+		assert!( futures::poll!( &mut send ).is_ready() );
+	}
+
+	drop( ph );
+
+	assert_eq!( Some( 3 ), events.next().await );
+	assert_eq!( None     , events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn close()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe_expand( Channel::Bounded( 1 ).into(), digits ).await.expect( "observe" );
+
+	assert!( futures::poll!( ph.send( 123 ) ).is_pending() );
+
+	ph.close().await.expect( "close" );
+
+	assert_eq!( Some( 1 ), events.next().await );
+	assert_eq!( None     , events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph         = SharedPharos::<usize>::default();
+	let events     = ph.observe_expand( ObserveConfig::default(), digits ).await.expect( "observe" );
+
+	ph.notify( 42 ).await.expect( "notify" );
+	drop( ph );
+
+	assert_eq!( vec![ 4, 2 ], events.collect::<Vec<_>>().await );
+}
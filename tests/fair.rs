@@ -0,0 +1,55 @@
+// Tested:
+//
+// ✔ by default observers are served in order.
+// ✔ with set_fair, the observer served first rotates with every notification.
+//
+mod common;
+
+use common::import::*;
+use std::sync::Mutex;
+
+
+// Returns the order in which the observers saw each event.
+//
+async fn serve_order( fair: bool ) -> Vec<usize>
+{
+	let mut ph = Pharos::<usize>::default();
+	let log    = Arc::new( Mutex::new( Vec::new() ) );
+	let mut rx = Vec::new();
+
+	ph.set_fair( fair );
+
+	for id in 0..3
+	{
+		let log  = log.clone();
+		let opts = ObserveConfig::default().filter_boxed( move |_| { log.lock().unwrap().push( id ); true } );
+
+		rx.push( ph.observe( opts ).await.expect( "observe" ) );
+	}
+
+	for evt in 0..3
+	{
+		ph.send( evt ).await.expect( "send" );
+	}
+
+	let order = log.lock().unwrap().clone();
+	order
+}
+
+
+
+#[ async_std::test ]
+//
+async fn not_fair()
+{
+	assert_eq!( vec![ 0, 1, 2,  0, 1, 2,  0, 1, 2 ], serve_order( false ).await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn fair()
+{
+	assert_eq!( vec![ 0, 1, 2,  1, 2, 0,  2, 0, 1 ], serve_order( true ).await );
+}
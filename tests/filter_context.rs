@@ -0,0 +1,36 @@
+// Tested:
+//
+// ✔ a filter with a context sees changes to the context made after observing.
+// ✔ several observers can share a context.
+//
+mod common;
+
+use common::import::*;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+
+
+#[ async_std::test ]
+//
+async fn context()
+{
+	let min    = Arc::new( AtomicUsize::new( 5 ) );
+	let mut ph = Pharos::<usize>::default();
+
+	let above = |n: &usize, min: &AtomicUsize| *n >= min.load( Ordering::SeqCst );
+	let below = |n: &usize, min: &AtomicUsize| *n <  min.load( Ordering::SeqCst );
+
+	let high = ph.observe( Filter::with_context( min.clone(), above ).into() ).await.expect( "observe" );
+	let low  = ph.observe( Filter::with_context( min.clone(), below ).into() ).await.expect( "observe" );
+
+	ph.send( 3 ).await.expect( "send" );
+	ph.send( 6 ).await.expect( "send" );
+
+	min.store( 2, Ordering::SeqCst );
+
+	ph.send( 3 ).await.expect( "send" );
+	ph.send( 1 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 6, 3 ], high.collect::<Vec<_>>().await );
+	assert_eq!( vec![ 3, 1 ], low .collect::<Vec<_>>().await );
+}
@@ -0,0 +1,40 @@
+// Tested:
+//
+// ✔ finish returns the pending events in order and disconnects from the observable.
+// ✔ finish on a bounded channel returns everything that was buffered.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn finish()
+{
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+
+	assert_eq!( vec![ 1, 2 ], events.finish().await );
+
+	ph.send( 3 ).await.expect( "send" );
+
+	assert_eq!( 0, ph.num_observers() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn bounded()
+{
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe( Channel::Bounded( 3 ).into() ).await.expect( "observe" );
+
+	for i in 0..3 { ph.send( i ).await.expect( "send" ); }
+
+	assert_eq!( vec![ 0, 1, 2 ], events.finish().await );
+}
@@ -0,0 +1,53 @@
+// Tested:
+//
+// ✔ every event is handled in order and the future resolves when the observable is dropped.
+// ✔ events buffered when the observable is closed are handled before the future resolves.
+//
+mod common;
+
+use common::import::*;
+use std::sync::Mutex as SyncMutex;
+
+
+#[ async_std::test ]
+//
+async fn dropped()
+{
+	let seen   = Arc::new( SyncMutex::new( Vec::new() ) );
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	let record = seen.clone();
+	let task   = async_std::task::spawn( events.for_each_until_closed( move |n|
+	{
+		record.lock().unwrap().push( n );
+		async {}
+	}));
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	drop( ph );
+
+	task.await;
+
+	assert_eq!( vec![ 1, 2 ], *seen.lock().unwrap() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn closed()
+{
+	let mut seen = Vec::new();
+	let mut ph   = Pharos::<usize>::default();
+	let events   = ph.observe( Channel::Bounded( 5 ).into() ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	ph.close().await.expect( "close" );
+
+	events.for_each_until_closed( |n| { seen.push( n ); async {} } ).await;
+
+	assert_eq!( vec![ 1, 2 ], seen );
+}
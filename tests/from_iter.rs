@@ -0,0 +1,76 @@
+// Tested:
+//
+// ✔ collecting an iterator into Events delivers it's items and then ends.
+// ✔ Events::from_receiver with a bounded and an unbounded channel.
+// ✔ closing Events created from a receiver closes the channel.
+// ✔ adapters work on such Events.
+//
+mod common;
+
+use common::import::*;
+use futures::channel::mpsc;
+use std::iter::FromIterator;
+
+
+// A consumer under test.
+//
+async fn sum( events: Events<usize> ) -> usize
+{
+	events.fold( 0, |acc, n| async move { acc + n } ).await
+}
+
+
+
+#[ async_std::test ]
+//
+async fn from_iter()
+{
+	assert_eq!( 6, sum( vec![ 1, 2, 3 ].into_iter().collect() ).await );
+	assert_eq!( 0, sum( Events::from_iter( None ) ).await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn from_receiver()
+{
+	let (mut tx, rx) = mpsc::channel( 5 );
+	let (utx, urx)   = mpsc::unbounded();
+
+	tx.send( 1 ).await.expect( "send" );
+	utx.unbounded_send( 2 ).expect( "send" );
+
+	drop(( tx, utx ));
+
+	assert_eq!( 1, sum( Events::from_receiver( rx  ) ).await );
+	assert_eq!( 2, sum( Events::from_receiver( urx ) ).await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn close()
+{
+	let (tx, rx)   = mpsc::unbounded::<usize>();
+	let mut events = Events::from_receiver( rx );
+
+	events.close();
+
+	assert!( tx.unbounded_send( 1 ).is_err() );
+	assert_eq!( None, events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn adapter()
+{
+	let events: Events<usize> = vec![ 1, 1, 2 ].into_iter().collect();
+
+	let compacted = events.compact( std::time::Duration::from_secs( 10 ), async_std::task::sleep );
+
+	assert_eq!( vec![ ( 1, 2 ), ( 2, 1 ) ], compacted.collect::<Vec<_>>().await );
+}
@@ -0,0 +1,61 @@
+// Tested:
+//
+// ✔ events are skipped while the gate is closed and delivery resumes when it opens.
+// ✔ skipped events are counted as dropped, filtered events are not.
+// ✔ one gate can be shared by several observers.
+// ✔ the count survives a combinator.
+//
+mod common;
+
+use common::import::*;
+use std::sync::atomic::{ AtomicBool, Ordering };
+
+
+#[ async_std::test ]
+//
+async fn gate()
+{
+	let mut ph = Pharos::<usize>::default();
+	let gate   = Arc::new( AtomicBool::new( true ) );
+
+	let mut all  = ph.observe( ObserveConfig::default().gate( gate.clone() )                       ).await.expect( "observe" );
+	let mut even = ph.observe( ObserveConfig::default().gate( gate.clone() ).filter( |e| e % 2 == 0 ) ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	gate.store( false, Ordering::Relaxed );
+
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+
+	gate.store( true, Ordering::Relaxed );
+
+	ph.send( 4 ).await.expect( "send" );
+
+	drop( ph );
+
+	assert_eq!( 2, all .dropped() );
+	assert_eq!( 1, even.dropped() );
+
+	assert_eq!( Some( 1 ), all.next().await );
+	assert_eq!( Some( 4 ), all.next().await );
+	assert_eq!( None     , all.next().await );
+
+	assert_eq!( Some( 4 ), even.next().await );
+	assert_eq!( None     , even.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn combinator()
+{
+	let mut ph = Pharos::<usize>::default();
+	let gate   = Arc::new( AtomicBool::new( false ) );
+	let events = ph.observe( ObserveConfig::default().gate( gate ) ).await.expect( "observe" ).derive( |e| e );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	assert_eq!( 1, events.dropped() );
+}
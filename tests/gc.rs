@@ -0,0 +1,66 @@
+// Tested:
+//
+// ✔ gc removes observers that went away without notifying and returns how many.
+// ✔ observing reuses the slots of observers that went away.
+// ✔ reconnectable observers are kept.
+// ✔ SharedPharos::gc.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn gc()
+{
+	let mut ph    = Pharos::<usize>::default();
+	let mut cycle = ph.lifecycle_events();
+	let a         = ph.observe( ObserveConfig::default()                 ).await.expect( "observe" );
+	let b         = ph.observe( Channel::Bounded( 1 ).into()             ).await.expect( "observe" );
+	let _c        = ph.observe( ObserveConfig::default()                 ).await.expect( "observe" );
+	let mut d     = ph.observe( ObserveConfig::default().reconnectable() ).await.expect( "observe" );
+	let id        = a.id();
+
+	drop(( a, b ));
+	d.close();
+
+	assert_eq!( 2, ph.gc() );
+	assert_eq!( 0, ph.gc() );
+
+	for _ in 0..4 { cycle.next().await; }
+
+	assert_eq!( Some( LifecycleEvent::Unsubscribed{ id, reason: UnsubscribeReason::Disconnected } ), cycle.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn observe()
+{
+	let mut ph = Pharos::<usize>::default();
+
+	for _ in 0..10
+	{
+		let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+		drop( events );
+	}
+
+	assert_eq!( 1, ph.storage_len() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph     = SharedPharos::<usize>::default();
+	let events = ph.observe_shared( ObserveConfig::default() ).await.expect( "observe" );
+
+	drop( events );
+
+	assert_eq!( 1, ph.gc().await );
+}
@@ -0,0 +1,56 @@
+// Tested:
+//
+// ✔ notify_group only reaches observers in that group.
+// ✔ grouped observers still receive events sent to everyone.
+// ✔ filters still apply to group notifications.
+//
+mod common;
+
+use common::{ *, import::* };
+
+
+#[ async_std::test ]
+//
+async fn notify_group()
+{
+	let mut ph = Pharos::<IsisEvent>::default();
+
+	let mut a    = ph.observe( ObserveConfig::default().group( 1 ) ).await.expect( "observe" );
+	let mut b    = ph.observe( ObserveConfig::default().group( 2 ) ).await.expect( "observe" );
+	let mut none = ph.observe( ObserveConfig::default()            ).await.expect( "observe" );
+
+	ph.notify_group( GroupId(1), &IsisEvent::Sail ).await.expect( "notify_group" );
+	ph.send( IsisEvent::Dock ).await.expect( "send" );
+
+	drop( ph );
+
+	assert_eq!( IsisEvent::Sail, a.next().await.unwrap() );
+	assert_eq!( IsisEvent::Dock, a.next().await.unwrap() );
+	assert_eq!( None           , a.next().await          );
+
+	assert_eq!( IsisEvent::Dock, b.next().await.unwrap() );
+	assert_eq!( None           , b.next().await          );
+
+	assert_eq!( IsisEvent::Dock, none.next().await.unwrap() );
+	assert_eq!( None           , none.next().await          );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn notify_group_filter()
+{
+	let mut ph = Pharos::<IsisEvent>::default();
+
+	let opts       = ObserveConfig::default().group( 1 ).filter( |e| *e == IsisEvent::Dock );
+	let mut events = ph.observe( opts ).await.expect( "observe" );
+
+	ph.notify_group( GroupId(1), &IsisEvent::Sail ).await.expect( "notify_group" );
+	ph.notify_group( GroupId(1), &IsisEvent::Dock ).await.expect( "notify_group" );
+
+	drop( ph );
+
+	assert_eq!( IsisEvent::Dock, events.next().await.unwrap() );
+	assert_eq!( None           , events.next().await          );
+}
@@ -0,0 +1,57 @@
+// Tested:
+//
+// ✔ a heartbeat is yielded when no event arrives within the interval.
+// ✔ real events are passed through and reset the timer.
+// ✔ the stream ends when the observable ends it, without a heartbeat.
+//
+mod common;
+
+use common::import::*;
+use std::time::{ Duration, Instant };
+
+
+const BEAT: usize = 0;
+
+
+
+#[ async_std::test ]
+//
+async fn heartbeat()
+{
+	let dur        = Duration::from_millis( 20 );
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).with_heartbeat( dur, || BEAT, async_std::task::sleep );
+
+	let start = Instant::now();
+
+	assert_eq!( Some( BEAT ), events.next().await );
+
+		assert!( start.elapsed() >= dur );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	assert_eq!( Some( 1    ), events.next().await );
+	assert_eq!( Some( BEAT ), events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn reset()
+{
+	let dur        = Duration::from_millis( 50 );
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).with_heartbeat( dur, || BEAT, async_std::task::sleep );
+
+	for i in 1..4
+	{
+		ph.send( i ).await.expect( "send" );
+
+		assert_eq!( Some( i ), events.next().await );
+	}
+
+	drop( ph );
+
+	assert_eq!( None, events.next().await );
+}
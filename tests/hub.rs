@@ -0,0 +1,58 @@
+// Tested:
+//
+// ✔ handles notify and subscribe from several tasks.
+// ✔ dropping the hub ends the subscriptions and handles return ErrorKind::Closed.
+// ✔ closing the hub ends the subscriptions and handles return ErrorKind::Closed.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn hub()
+{
+	let hub    = Hub::<usize>::default();
+	let handle = hub.handle();
+	let events = handle.subscribe( ObserveConfig::default() ).await.expect( "subscribe" );
+
+	let tasks: Vec<_> = ( 0..3 ).map( |i|
+	{
+		let handle = handle.clone();
+
+		async_std::task::spawn( async move { handle.notify( i ).await.expect( "notify" ) } )
+
+	}).collect();
+
+	for t in tasks { assert_eq!( 1, t.await ); }
+
+	drop( hub );
+
+	let mut seen = events.collect::<Vec<_>>().await;
+	seen.sort_unstable();
+
+	assert_eq!( vec![ 0, 1, 2 ], seen );
+
+	assert_eq!( ErrorKind::Closed, handle.notify( 3 ).await.unwrap_err().kind() );
+	assert_eq!( ErrorKind::Closed, handle.subscribe( ObserveConfig::default() ).await.unwrap_err().kind() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn close()
+{
+	let hub        = Hub::<usize>::default();
+	let handle     = hub.handle();
+	let mut events = handle.subscribe( ObserveConfig::default() ).await.expect( "subscribe" );
+
+	handle.notify( 1 ).await.expect( "notify" );
+	hub.close().await.expect( "close" );
+
+	assert_eq!( Some( 1 ), events.next().await );
+	assert_eq!( None     , events.next().await );
+
+	assert_eq!( ErrorKind::Closed, handle.notify( 2 ).await.unwrap_err().kind() );
+}
@@ -0,0 +1,107 @@
+// Tested:
+//
+// ✔ subscribing reports the id and name of the observer.
+// ✔ observers that drop their events are reported when the pharos notices.
+// ✔ closing reports all observers and ends the stream.
+// ✔ swap_observers reports the old observers as swapped and the new ones as subscribed.
+// ✔ SharedPharos::lifecycle_events.
+// ✔ lifecycle_events through a shared reference.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn subscribe()
+{
+	let mut ph        = Pharos::<usize>::default();
+	let mut lifecycle = ph.lifecycle_events();
+
+	let named = ph.observe( ObserveConfig::default().name( "named" ) ).await.expect( "observe" );
+	let anon  = ph.observe( ObserveConfig::default()                 ).await.expect( "observe" );
+
+	assert_ne!( named.id(), anon.id() );
+
+	assert_eq!( Some( LifecycleEvent::Subscribed{ id: named.id(), name: Some( "named".into() ) } ), lifecycle.next().await );
+	assert_eq!( Some( LifecycleEvent::Subscribed{ id: anon .id(), name: None                   } ), lifecycle.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn disconnect()
+{
+	let mut ph        = Pharos::<usize>::default();
+	let events        = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let id            = events.id();
+	let mut lifecycle = ph.lifecycle_events();
+
+	drop( events );
+	ph.send( 1 ).await.expect( "send" );
+
+	assert_eq!( Some( LifecycleEvent::Unsubscribed{ id, reason: UnsubscribeReason::Disconnected } ), lifecycle.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn close()
+{
+	let mut ph        = Pharos::<usize>::default();
+	let events        = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let mut lifecycle = ph.lifecycle_events();
+
+	ph.close().await.expect( "close" );
+
+	assert_eq!( Some( LifecycleEvent::Unsubscribed{ id: events.id(), reason: UnsubscribeReason::Closed } ), lifecycle.next().await );
+	assert_eq!( None, lifecycle.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn swap()
+{
+	let mut ph        = Pharos::<usize>::default();
+	let mut set       = ObserverSet::default();
+	let old           = ph .observe( ObserveConfig::default() ).await.expect( "observe" );
+	let new           = set.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let mut lifecycle = ph.lifecycle_events();
+
+	let _old = ph.swap_observers( set );
+
+	assert_eq!( Some( LifecycleEvent::Unsubscribed{ id: old.id(), reason: UnsubscribeReason::Swapped } ), lifecycle.next().await );
+	assert_eq!( Some( LifecycleEvent::Subscribed  { id: new.id(), name  : None                       } ), lifecycle.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph            = SharedPharos::<usize>::default();
+	let mut lifecycle = ph.lifecycle_events().await;
+	let events        = ph.observe_shared( ObserveConfig::default() ).await.expect( "observe" );
+
+	assert_eq!( Some( LifecycleEvent::Subscribed{ id: events.id(), name: None } ), lifecycle.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn by_ref()
+{
+	let mut ph        = Pharos::<usize>::default();
+	let shared        = &ph;
+	let mut lifecycle = shared.lifecycle_events();
+	let events        = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	assert_eq!( Some( LifecycleEvent::Subscribed{ id: events.id(), name: None } ), lifecycle.next().await );
+}
@@ -0,0 +1,48 @@
+// Tested:
+//
+// ✔ every notification is logged with the event type and the number of observers reached.
+//
+#![ cfg( feature = "log" ) ]
+
+mod common;
+
+use common::{ *, import::* };
+use std::sync::Mutex;
+
+
+static LINES: Mutex<Vec<String>> = Mutex::new( Vec::new() );
+
+
+struct Capture;
+
+impl log::Log for Capture
+{
+	fn enabled( &self, _: &log::Metadata<'_> ) -> bool { true }
+
+	fn log( &self, record: &log::Record<'_> )
+	{
+		LINES.lock().unwrap().push( record.args().to_string() );
+	}
+
+	fn flush( &self ) {}
+}
+
+
+
+#[ async_std::test ]
+//
+async fn log()
+{
+	log::set_logger( &Capture ).expect( "set logger" );
+	log::set_max_level( log::LevelFilter::Trace );
+
+	let mut isis = Goddess::new();
+	let _a       = isis.observe( ObserveConfig::<IsisEvent>::default()                                    ).await.expect( "observe" );
+	let _b       = isis.observe( ObserveConfig::<IsisEvent>::default().filter( |e| *e == IsisEvent::Dock ) ).await.expect( "observe" );
+
+	isis.sail().await;
+
+	let lines = LINES.lock().unwrap();
+
+	assert!( lines.iter().any( |l| l == "pharos::Pharos<log::common::IsisEvent>: notifying 1 observer(s)" ), "{:?}", lines );
+}
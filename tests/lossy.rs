@@ -0,0 +1,102 @@
+// Tested:
+//
+// ✔ events are delivered as Lossy::Event when nothing is lost.
+// ✔ events skipped by the gate are reported by a Lossy::Lag right before the next event.
+// ✔ on a bounded channel, the event after a lag waits for room.
+// ✔ closing the pharos reports what was lost since the last item.
+// ✔ SharedPharos::observe_lossy.
+//
+mod common;
+
+use common::import::*;
+use std::sync::atomic::{ AtomicBool, Ordering };
+
+
+#[ async_std::test ]
+//
+async fn lag()
+{
+	let gate   = Arc::new( AtomicBool::new( true ) );
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe_lossy( ObserveConfig::default().gate( gate.clone() ) ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	gate.store( false, Ordering::SeqCst );
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+
+	gate.store( true, Ordering::SeqCst );
+	ph.send( 4 ).await.expect( "send" );
+	ph.send( 5 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!
+	(
+		vec![ Lossy::Event( 1 ), Lossy::Lag( 2 ), Lossy::Event( 4 ), Lossy::Event( 5 ) ],
+		events.collect::<Vec<_>>().await
+	);
+}
+
+
+
+#[ async_std::test ]
+//
+async fn bounded()
+{
+	let gate       = Arc::new( AtomicBool::new( false ) );
+	let mut ph     = Pharos::<usize>::default();
+	let opts       = ObserveConfig::from( Channel::Bounded( 1 ) ).gate( gate.clone() );
+	let mut events = ph.observe_lossy( opts ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	gate.store( true, Ordering::SeqCst );
+
+	{
+		let mut send = ph.send( 2 );
+
+		assert!( futures::poll!( &mut send ).is_pending() );
+		assert_eq!( Some( Lossy::Lag( 1 ) ), events.next().await );
+		assert!( futures::poll!( &mut send ).is_ready() );
+	}
+
+	drop( ph );
+
+	assert_eq!( Some( Lossy::Event( 2 ) ), events.next().await );
+	assert_eq!( None                     , events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn close()
+{
+	let gate   = Arc::new( AtomicBool::new( true ) );
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe_lossy( ObserveConfig::default().gate( gate.clone() ) ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	gate.store( false, Ordering::SeqCst );
+	ph.send( 2 ).await.expect( "send" );
+
+	ph.close().await.expect( "close" );
+
+	assert_eq!( vec![ Lossy::Event( 1 ), Lossy::Lag( 1 ) ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph     = SharedPharos::<usize>::default();
+	let events = ph.observe_lossy( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.notify( 1 ).await.expect( "notify" );
+	drop( ph );
+
+	assert_eq!( vec![ Lossy::Event( 1 ) ], events.collect::<Vec<_>>().await );
+}
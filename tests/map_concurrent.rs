@@ -0,0 +1,108 @@
+// Tested:
+//
+// ✔ unordered: results come out as they complete.
+// ✔ ordered: results come out in the order of the events.
+// ✔ no more than `concurrency` futures run at the same time.
+// ✔ closing lets running futures finish and delivers their results.
+//
+mod common;
+
+use common::import::*;
+use std::{ time::Duration, sync::atomic::{ AtomicUsize, Ordering } };
+
+
+// Sleep longer for smaller numbers, so they complete in reverse order.
+//
+async fn reverse( n: usize ) -> usize
+{
+	async_std::task::sleep( Duration::from_millis( 10 * ( 4 - n ) as u64 ) ).await;
+	n
+}
+
+
+
+#[ async_std::test ]
+//
+async fn unordered()
+{
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).map_concurrent( 3, reverse );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 3, 2, 1 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn ordered()
+{
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).map_concurrent_ordered( 3, reverse );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2, 3 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn concurrency()
+{
+	let running = Arc::new( AtomicUsize::new( 0 ) );
+	let max     = Arc::new( AtomicUsize::new( 0 ) );
+	let mut ph  = Pharos::<usize>::default();
+
+	let (r, m) = ( running.clone(), max.clone() );
+
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).map_concurrent( 2, move |n|
+	{
+		let (r, m) = ( r.clone(), m.clone() );
+
+		async move
+		{
+			m.fetch_max( r.fetch_add( 1, Ordering::SeqCst ) + 1, Ordering::SeqCst );
+			async_std::task::sleep( Duration::from_millis( 5 ) ).await;
+			r.fetch_sub( 1, Ordering::SeqCst );
+			n
+		}
+	});
+
+	for i in 0..6 { ph.send( i ).await.expect( "send" ); }
+	drop( ph );
+
+	assert_eq!( 6, events.count().await         );
+	assert_eq!( 2, max.load( Ordering::SeqCst ) );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn close()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).map_concurrent( 2, reverse );
+
+	ph.send( 3 ).await.expect( "send" );
+
+	// Start the future before closing.
+	//
+	assert!( futures::poll!( events.next() ).is_pending() );
+
+	events.close();
+	ph.send( 2 ).await.expect( "send" );
+
+	assert_eq!( Some( 3 ), events.next().await );
+	assert_eq!( None     , events.next().await );
+}
@@ -0,0 +1,68 @@
+// Tested:
+//
+// ✔ an event seen from several streams is delivered once, the first time it arrives.
+// ✔ a key is delivered again after the window has elapsed.
+// ✔ the result ends when all streams have ended.
+//
+mod common;
+
+use common::import::*;
+use std::time::Duration;
+
+
+#[ async_std::test ]
+//
+async fn dedup()
+{
+	let mut primary = Pharos::<usize>::default();
+	let mut backup  = Pharos::<usize>::default();
+
+	let streams = vec!
+	[
+		primary.observe( ObserveConfig::default() ).await.expect( "observe" ),
+		backup .observe( ObserveConfig::default() ).await.expect( "observe" ),
+	];
+
+	let merged = Events::merge_dedup( streams, |n| *n, Duration::from_secs( 60 ), async_std::task::sleep );
+
+	primary.send( 1 ).await.expect( "send" );
+	primary.send( 2 ).await.expect( "send" );
+	backup .send( 1 ).await.expect( "send" );
+	backup .send( 3 ).await.expect( "send" );
+	backup .send( 2 ).await.expect( "send" );
+	primary.send( 3 ).await.expect( "send" );
+
+	drop( primary );
+	drop( backup  );
+
+	assert_eq!( vec![ 1, 2, 3 ], merged.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn window()
+{
+	let window     = Duration::from_millis( 20 );
+	let mut ph     = Pharos::<usize>::default();
+	let streams    = vec![ ph.observe( ObserveConfig::default() ).await.expect( "observe" ) ];
+	let mut merged = Events::merge_dedup( streams, |n| *n, window, async_std::task::sleep );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 1 ).await.expect( "send" );
+
+	assert_eq!( Some( 1 ), merged.next().await );
+
+	// Reads the duplicate.
+	//
+	assert!( futures::poll!( merged.next() ).is_pending() );
+
+	async_std::task::sleep( window * 2 ).await;
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2 ], merged.collect::<Vec<_>>().await );
+}
@@ -0,0 +1,28 @@
+// Tested:
+//
+// ✔ skipped events are discarded.
+// ✔ resolves to None when the stream ends without a match.
+//
+mod common;
+
+use common::{ *, import::* };
+
+
+#[ async_std::test ]
+//
+async fn next_where()
+{
+	let mut isis   = Goddess::new();
+	let mut events = isis.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	isis.sail().await;
+	isis.sail().await;
+	isis.dock().await;
+	isis.sail().await;
+
+	drop( isis );
+
+	assert_eq!( Some( IsisEvent::Dock ), events.next_where( |e| *e == IsisEvent::Dock ).await );
+	assert_eq!( Some( IsisEvent::Sail ), events.next().await                                 );
+	assert_eq!( None                   , events.next_where( |e| *e == IsisEvent::Dock ).await );
+}
@@ -0,0 +1,72 @@
+// Tested:
+//
+// ✔ the condition is not evaluated without observers.
+// ✔ the event is only sent when the condition returns true.
+// ✔ a closed pharos returns an error without evaluating the condition.
+// ✔ SharedPharos::notify_if.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn unobserved()
+{
+	let mut ph = Pharos::<usize>::default();
+
+	ph.notify_if( &1, || panic!( "condition evaluated without observers" ) ).await.expect( "notify" );
+
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	drop( events );
+
+	ph.notify_if( &1, || panic!( "condition evaluated without observers" ) ).await.expect( "notify" );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn condition()
+{
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.notify_if( &1, || false ).await.expect( "notify" );
+	ph.notify_if( &2, || true  ).await.expect( "notify" );
+	drop( ph );
+
+	assert_eq!( vec![ 2 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn closed()
+{
+	let mut ph  = Pharos::<usize>::default();
+	let _events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.close().await.expect( "close" );
+
+	let res = ph.notify_if( &1, || panic!( "condition evaluated on a closed pharos" ) ).await;
+
+	assert_eq!( ErrorKind::Closed, res.unwrap_err().kind() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph     = SharedPharos::<usize>::default();
+	let events = ph.observe_shared( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.notify_if( &1, || true ).await.expect( "notify" );
+	drop( ph );
+
+	assert_eq!( vec![ 1 ], events.collect::<Vec<_>>().await );
+}
@@ -0,0 +1,55 @@
+// Tested:
+//
+// ✔ the handler is called with the id of the observer when sending to it fails, and the observer is removed.
+// ✔ the handler is not called for observers that went away.
+//
+mod common;
+
+use common::import::*;
+use std::sync::Mutex as SyncMutex;
+
+
+#[ async_std::test ]
+//
+async fn on_error()
+{
+	let errors = Arc::new( SyncMutex::new( Vec::new() ) );
+	let record = errors.clone();
+	let mut ph = Pharos::<usize>::default();
+
+	ph.on_error( move |id, err| record.lock().unwrap().push(( id, *err.kind() )) );
+
+	let full = ph.observe( Channel::Bounded( 1 ).into() ).await.expect( "observe" );
+	let _ok  = ph.observe( ObserveConfig::default()     ).await.expect( "observe" );
+
+	// Without waiting for the channel to be ready, the second event doesn't fit.
+	//
+	Pin::new( &mut ph ).start_send( 1 ).expect( "start_send" );
+	Pin::new( &mut ph ).start_send( 2 ).expect( "start_send" );
+
+	assert_eq!( vec![( full.id(), ErrorKind::SendError )], *errors.lock().unwrap() );
+	assert_eq!( 1, ph.num_observers() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn disconnect()
+{
+	let errors = Arc::new( SyncMutex::new( Vec::new() ) );
+	let record = errors.clone();
+	let mut ph = Pharos::<usize>::default();
+
+	ph.on_error( move |id, _| record.lock().unwrap().push( id ) );
+
+	let events = ph.observe( Channel::Bounded( 1 ).into() ).await.expect( "observe" );
+	let _other = ph.observe( ObserveConfig::default()     ).await.expect( "observe" );
+
+	drop( events );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	assert!( errors.lock().unwrap().is_empty() );
+	assert_eq!( 1, ph.num_observers() );
+}
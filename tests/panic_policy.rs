@@ -0,0 +1,109 @@
+// Tested:
+//
+// ✔ with PanicPolicy::Isolate a panicking filter unsubscribes the observer, the others still get the event.
+// ✔ the observer is reported as panicked to lifecycle events and the error handler.
+// ✔ a panic in the function of observe_expand is isolated as well.
+// ✔ a panicking reducer of Channel::Reduce doesn't break the stream of the observer, the lost event counts as dropped.
+// ✔ with PanicPolicy::Propagate the panic unwinds through the notifier.
+//
+mod common;
+
+use common::import::*;
+use std::{ panic::{ catch_unwind, AssertUnwindSafe }, sync::Mutex as SyncMutex };
+
+
+fn panic_on_two( evt: &usize ) -> bool
+{
+	assert_ne!( 2, *evt, "observer panics on purpose" );
+	true
+}
+
+
+
+#[ async_std::test ]
+//
+async fn isolate()
+{
+	let errors = Arc::new( SyncMutex::new( Vec::new() ) );
+	let record = errors.clone();
+	let mut ph = Pharos::<usize>::default();
+
+	ph.on_error( move |id, err| record.lock().unwrap().push(( id, *err.kind() )) );
+
+	let opts          = ObserveConfig::default().filter( panic_on_two ).panic_policy( PanicPolicy::Isolate );
+	let panics        = ph.observe( opts                     ).await.expect( "observe" );
+	let other         = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let mut lifecycle = ph.lifecycle_events();
+
+	for i in 1..4 { ph.send( i ).await.expect( "send" ); }
+
+	let id = panics.id();
+	drop( ph );
+
+	assert_eq!( vec![ 1       ], panics.collect::<Vec<_>>().await );
+	assert_eq!( vec![ 1, 2, 3 ], other .collect::<Vec<_>>().await );
+
+	assert_eq!( vec![( id, ErrorKind::Panicked )], *errors.lock().unwrap() );
+	assert_eq!( Some( LifecycleEvent::Unsubscribed{ id, reason: UnsubscribeReason::Panicked } ), lifecycle.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn expand()
+{
+	let mut ph = Pharos::<usize>::default();
+	let opts   = ObserveConfig::default().panic_policy( PanicPolicy::Isolate );
+	let events = ph.observe_expand( opts, |evt: &usize| vec![ 10 / *evt ] ).await.expect( "observe" );
+
+	ph.send( 5 ).await.expect( "send" );
+	ph.send( 0 ).await.expect( "send" );
+
+	assert_eq!( 0, ph.num_observers() );
+
+	drop( ph );
+
+	assert_eq!( vec![ 2 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn reduce()
+{
+	let mut ph = Pharos::<usize>::default();
+
+	let opts = ObserveConfig::default()
+
+		.channel     ( Channel::Reduce                                                           )
+		.reduce      ( |_pending, new| { assert_ne!( 3, new, "observer panics on purpose" ); new } )
+		.panic_policy( PanicPolicy::Isolate                                                      )
+	;
+
+	let mut events = ph.observe( opts ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	assert_eq!( Some( 1 ), events.next().await );
+
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+
+	assert_eq!( 0   , ph.num_observers() );
+	assert_eq!( 1   , events.dropped()   );
+	assert_eq!( None, events.next().await );
+}
+
+
+
+#[ test ]
+//
+fn propagate()
+{
+	let mut ph = Pharos::<usize>::default();
+	let _obs   = block_on( ph.observe( ObserveConfig::default().filter( panic_on_two ) ) ).expect( "observe" );
+
+	assert!( catch_unwind( AssertUnwindSafe( || block_on( ph.send( 2 ) ) ) ).is_err() );
+}
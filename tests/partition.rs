@@ -0,0 +1,128 @@
+// Tested:
+//
+// ✔ every key gets a child stream with it's events, in order.
+// ✔ children end when the observable ends the stream.
+// ✔ events for a dropped child are discarded and no new child is created for it's key.
+// ✔ after the partition is dropped, existing children keep receiving events.
+// ✔ children handled in separate tasks get woken up for new events.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn partition()
+{
+	let mut ph        = Pharos::<usize>::default();
+	let mut partition = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).partition_by( |e| e % 2 );
+
+	for i in 0..6
+	{
+		ph.send( i ).await.expect( "send" );
+	}
+
+	drop( ph );
+
+	let (even_key, even) = partition.next().await.unwrap();
+	let (odd_key , odd ) = partition.next().await.unwrap();
+
+	assert_eq!( 0, even_key );
+	assert_eq!( 1, odd_key  );
+
+	assert!( partition.next().await.is_none() );
+
+	assert_eq!( vec![ 0, 2, 4 ], even.collect::<Vec<_>>().await );
+	assert_eq!( vec![ 1, 3, 5 ], odd .collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn drop_child()
+{
+	let mut ph        = Pharos::<usize>::default();
+	let mut partition = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).partition_by( |e| e % 2 );
+
+	ph.send( 0 ).await.expect( "send" );
+	ph.send( 1 ).await.expect( "send" );
+
+	let (_, mut even) = partition.next().await.unwrap();
+	let (_,     odd ) = partition.next().await.unwrap();
+
+	drop( odd );
+
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+
+	assert_eq!( Some( 0 ), even.next().await );
+	assert_eq!( Some( 2 ), even.next().await );
+
+	drop( ph );
+
+	assert!( partition.next().await.is_none() );
+	assert_eq!( None, even.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn drop_partition()
+{
+	let mut ph        = Pharos::<usize>::default();
+	let mut partition = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).partition_by( |e| e % 3 );
+
+	ph.send( 0 ).await.expect( "send" );
+	ph.send( 1 ).await.expect( "send" );
+
+	let (_, zero) = partition.next().await.unwrap();
+
+	// The child for key 1 was not handed out yet.
+	//
+	drop( partition );
+
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 0, 3 ], zero.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn tasks()
+{
+	let mut ph        = Pharos::<usize>::default();
+	let mut partition = ph.observe( ObserveConfig::default() ).await.expect( "observe" ).partition_by( |e| e % 2 );
+
+	let router = async_std::task::spawn( async move
+	{
+		let mut handles = Vec::new();
+
+		while let Some(( key, child )) = partition.next().await
+		{
+			handles.push( async_std::task::spawn( async move { ( key, child.collect::<Vec<_>>().await ) } ) );
+		}
+
+		let mut results = Vec::new();
+
+		for h in handles { results.push( h.await ); }
+
+		results
+	});
+
+	for i in 0..6
+	{
+		ph.send( i ).await.expect( "send" );
+		async_std::task::yield_now().await;
+	}
+
+	drop( ph );
+
+	assert_eq!( vec![ (0, vec![ 0, 2, 4 ]), (1, vec![ 1, 3, 5 ]) ], router.await );
+}
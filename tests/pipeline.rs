@@ -0,0 +1,156 @@
+// Tested:
+//
+// ✔ the selection of the notification comes before the filter, the filter before the gate and
+//   the gate before the transformation.
+// ✔ events that don't pass the filter don't count as dropped by the gate.
+// ✔ an event is cloned for every observer that receives it but the last, never for filtering.
+// ✔ notify_group clones the event for every observer in the group.
+// ✔ the buffer limit applies the same steps to decide who counts a dropped event.
+//
+mod common;
+
+use common::import::*;
+use std::sync::{ Mutex as SyncMutex, atomic::{ AtomicBool, AtomicUsize, Ordering } };
+
+
+static CLONES: AtomicUsize = AtomicUsize::new( 0 );
+
+
+// Counts how often it is cloned. Only one test uses it, so they don't interfere.
+//
+#[ derive( Debug, PartialEq ) ]
+//
+struct Counted( usize );
+
+impl Clone for Counted
+{
+	fn clone( &self ) -> Self
+	{
+		CLONES.fetch_add( 1, Ordering::SeqCst );
+
+		Counted( self.0 )
+	}
+}
+
+
+
+type Log = Arc<SyncMutex< Vec<&'static str> >>;
+
+
+fn logged( log: &Log, step: &'static str, pass: bool ) -> bool
+{
+	log.lock().unwrap().push( step );
+	pass
+}
+
+
+
+#[ async_std::test ]
+//
+async fn order()
+{
+	let log    = Log::default();
+	let gate   = Arc::new( AtomicBool::new( true ) );
+	let mut ph = Pharos::<usize>::default();
+
+	let (f, t) = ( log.clone(), log.clone() );
+
+	let opts = ObserveConfig::default()
+
+		.group       ( 1                                        )
+		.gate        ( gate.clone()                             )
+		.filter_boxed( move |n| logged( &f, "filter", *n > 1 ) )
+	;
+
+	let events = ph.observe_with_output( opts, move |n| logged( &t, "transform", true ).then_some( *n ) ).await.expect( "observe" );
+
+
+	// Not selected.
+	//
+	ph.notify_group( 2.into(), &5 ).await.expect( "notify" );
+	assert!( log.lock().unwrap().is_empty() );
+
+	// Filtered out.
+	//
+	ph.send( 1 ).await.expect( "send" );
+	assert_eq!( vec![ "filter" ], std::mem::take( &mut *log.lock().unwrap() ) );
+
+	// Stopped by the gate.
+	//
+	gate.store( false, Ordering::SeqCst );
+	ph.send( 2 ).await.expect( "send" );
+	assert_eq!( vec![ "filter" ], std::mem::take( &mut *log.lock().unwrap() ) );
+
+	// All the way through.
+	//
+	gate.store( true, Ordering::SeqCst );
+	ph.send( 3 ).await.expect( "send" );
+	assert_eq!( vec![ "filter", "transform" ], std::mem::take( &mut *log.lock().unwrap() ) );
+
+	assert_eq!( 1, events.dropped() );
+
+	drop( ph );
+
+	assert_eq!( vec![ ( 3, 3 ) ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn clones()
+{
+	let mut ph = Pharos::<Counted>::default();
+
+	let a = ph.observe( ObserveConfig::default().group( 1 )                                 ).await.expect( "observe" );
+	let b = ph.observe( ObserveConfig::default().group( 1 ).filter( |c| c.0 > 0 )           ).await.expect( "observe" );
+	let c = ph.observe( ObserveConfig::default()                                            ).await.expect( "observe" );
+	let d = ph.observe( ObserveConfig::default().filter_boxed( |c: &Counted| c.0 > 9 )      ).await.expect( "observe" );
+
+	// a, b and c receive it, d filters it out.
+	//
+	ph.send( Counted( 1 ) ).await.expect( "send" );
+	assert_eq!( 2, CLONES.swap( 0, Ordering::SeqCst ) );
+
+	// b and d filter it out.
+	//
+	ph.send( Counted( 0 ) ).await.expect( "send" );
+	assert_eq!( 1, CLONES.swap( 0, Ordering::SeqCst ) );
+
+	// The group gets a clone each, because the event is only borrowed.
+	//
+	ph.notify_group( 1.into(), &Counted( 2 ) ).await.expect( "notify" );
+	assert_eq!( 2, CLONES.swap( 0, Ordering::SeqCst ) );
+
+	drop( ph );
+
+	assert_eq!( vec![ Counted( 1 ), Counted( 0 ), Counted( 2 ) ], a.collect::<Vec<_>>().await );
+	assert_eq!( vec![ Counted( 1 ),               Counted( 2 ) ], b.collect::<Vec<_>>().await );
+	assert_eq!( vec![ Counted( 1 ), Counted( 0 )               ], c.collect::<Vec<_>>().await );
+	assert_eq!( Vec::<Counted>::new()                           , d.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn limit()
+{
+	let gate   = Arc::new( AtomicBool::new( false ) );
+	let mut ph = Pharos::<usize>::default();
+
+	let full   = ph.observe( ObserveConfig::default()                             ).await.expect( "observe" );
+	let filter = ph.observe( ObserveConfig::default().filter( |n| *n > 5 )        ).await.expect( "observe" );
+	let group  = ph.observe( ObserveConfig::default().group( 1 )                  ).await.expect( "observe" );
+	let gated  = ph.observe( ObserveConfig::default().gate( gate )                ).await.expect( "observe" );
+
+	ph.send( 0 ).await.expect( "send" );
+	ph.set_buffer_limit( Some( 1 ), BufferPolicy::DropNewest );
+
+	ph.notify_group( 1.into(), &1 ).await.expect( "notify" );
+
+	assert_eq!( 0, full  .dropped() );
+	assert_eq!( 0, filter.dropped() );
+	assert_eq!( 1, group .dropped() );
+	assert_eq!( 1, gated .dropped() );
+}
@@ -0,0 +1,83 @@
+// Tested:
+//
+// ✔ prefilled events are delivered before live events, without going through the filter.
+// ✔ a bounded channel keeps as many prefilled events as it can hold.
+// ✔ prefilled events count towards the capacity of a bounded channel.
+// ✔ SharedPharos::observe with a prefill.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn prefill()
+{
+	let mut ph = Pharos::<usize>::default();
+	let opts   = ObserveConfig::default().filter( |n| *n > 5 ).prefill( vec![ 1, 2 ] );
+	let events = ph.observe( opts ).await.expect( "observe" );
+
+	ph.send( 3 ).await.expect( "send" );
+	ph.send( 6 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2, 6 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn truncate()
+{
+	let mut ph = Pharos::<usize>::default();
+	let opts   = ObserveConfig::from( Channel::Bounded( 2 ) ).prefill( vec![ 1, 2, 3 ] );
+	let events = ph.observe( opts ).await.expect( "observe" );
+
+	assert_eq!( 0, events.dropped() );
+
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn capacity()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let opts       = ObserveConfig::from( Channel::Bounded( 2 ) ).prefill( vec![ 1, 2 ] );
+	let mut events = ph.observe( opts ).await.expect( "observe" );
+
+	assert_eq!( 2, ph.buffered() );
+
+	{
+		let mut send = ph.send( 3 );
+
+		assert!( futures::poll!( &mut send ).is_pending() );
+		assert_eq!( Some( 1 ), events.next().await );
+		assert!( futures::poll!( &mut send ).is_ready() );
+	}
+
+	drop( ph );
+
+	assert_eq!( vec![ 2, 3 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph     = SharedPharos::<usize>::default();
+	let events = ph.observe_shared( ObserveConfig::default().prefill( vec![ 1 ] ) ).await.expect( "observe" );
+
+	ph.notify( 2 ).await.expect( "notify" );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2 ], events.collect::<Vec<_>>().await );
+}
@@ -0,0 +1,70 @@
+// Tested:
+//
+// ✔ history is delivered before live events, in order.
+// ✔ history comes before a backfill.
+// ✔ history doesn't count towards the capacity of a bounded channel.
+// ✔ SharedPharos::observe_prefixed.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn prefixed()
+{
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe_prefixed( ObserveConfig::default(), vec![ 1, 2 ] ).await.expect( "observe" );
+
+	ph.send( 3 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2, 3 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn backfill()
+{
+	let mut ph = Pharos::<usize>::default();
+	let opts   = ObserveConfig::default().backfill( || async { vec![ 2 ] } );
+	let events = ph.observe_prefixed( opts, vec![ 1 ] ).await.expect( "observe" );
+
+	ph.send( 3 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2, 3 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn bounded()
+{
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe_prefixed( Channel::Bounded( 1 ).into(), vec![ 1, 2, 3 ] ).await.expect( "observe" );
+
+	assert!( futures::poll!( ph.send( 4 ) ).is_ready() );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2, 3, 4 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph     = SharedPharos::<usize>::default();
+	let events = ph.observe_prefixed( ObserveConfig::default(), vec![ 1 ] ).await.expect( "observe" );
+
+	ph.notify( 2 ).await.expect( "notify" );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2 ], events.collect::<Vec<_>>().await );
+}
@@ -0,0 +1,98 @@
+// Tested:
+//
+// ✔ higher priority events are delivered first.
+// ✔ events with equal priority keep the order they were sent in.
+// ✔ buffered events are still delivered after close.
+// ✔ back pressure starts once the queue size of events are buffered, also after the observer started reading.
+// ✔ observe refuses Channel::Priority without a priority and Channel::Priority(0).
+//
+mod common;
+
+use common::{ *, import::* };
+
+
+#[ async_std::test ]
+//
+async fn priority()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let opts       = ObserveConfig::from( Channel::Priority( 5 ) ).priority( |e| *e as u64 );
+	let mut events = ph.observe( opts ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 5 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+
+	drop( ph );
+
+	assert_eq!( Some( 5 ), events.next().await );
+	assert_eq!( Some( 3 ), events.next().await );
+	assert_eq!( Some( 1 ), events.next().await );
+	assert_eq!( None     , events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn fifo_on_equal_priority()
+{
+	let mut ph     = Pharos::<IsisEvent>::default();
+	let opts       = ObserveConfig::from( Channel::Priority( 5 ) ).priority( |e| if *e == IsisEvent::Dock { 1 } else { 0 } );
+	let mut events = ph.observe( opts ).await.expect( "observe" );
+
+	ph.send( IsisEvent::Sail ).await.expect( "send" );
+	ph.send( IsisEvent::Sail ).await.expect( "send" );
+	ph.send( IsisEvent::Dock ).await.expect( "send" );
+
+	events.close();
+
+	assert_eq!( Some( IsisEvent::Dock ), events.next().await );
+	assert_eq!( Some( IsisEvent::Sail ), events.next().await );
+	assert_eq!( Some( IsisEvent::Sail ), events.next().await );
+	assert_eq!( None                   , events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn refuse_invalid()
+{
+	let mut ph = Pharos::<usize>::default();
+
+	let res = ph.observe( Channel::Priority( 5 ).into() ).await;
+
+		assert_eq!( ErrorKind::MissingPriority, res.unwrap_err().kind() );
+
+	let res = ph.observe( ObserveConfig::from( Channel::Priority( 0 ) ).priority( |e| *e as u64 ) ).await;
+
+		assert_eq!( ErrorKind::MinChannelSizeOne, res.unwrap_err().kind() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn back_pressure()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let opts       = ObserveConfig::from( Channel::Priority( 3 ) ).priority( |e| *e as u64 );
+	let mut events = ph.observe( opts ).await.expect( "observe" );
+
+	for i in 0..3 { ph.send( i ).await.expect( "send" ); }
+
+	assert!( futures::poll!( ph.send( 3 ) ).is_pending() );
+
+	// Reading moves the other events from the channel to the heap, they still count.
+	//
+	assert_eq!( Some( 2 ), events.next().await );
+
+	ph.send( 3 ).await.expect( "send" );
+
+	assert!( futures::poll!( ph.send( 4 ) ).is_pending() );
+
+	assert_eq!( Some( 3 ), events.next().await );
+	assert_eq!( Some( 1 ), events.next().await );
+	assert_eq!( Some( 0 ), events.next().await );
+}
@@ -0,0 +1,104 @@
+// Tested:
+//
+// ✔ notify returns the number of observers that accepted the event.
+// ✔ observers that filter the event out or are stopped by their gate don't count.
+// ✔ observers that went away don't count and are removed.
+// ✔ the single observer fast path and an event dropped by the buffer limit.
+// ✔ observers whose projection or output filter returns None, or that expand an event to nothing, don't count.
+// ✔ notify_group and notify_if return the reach as well.
+// ✔ SharedPharos::notify, notify_group and notify_if.
+//
+mod common;
+
+use common::import::*;
+use std::sync::atomic::AtomicBool;
+
+
+#[ async_std::test ]
+//
+async fn reach()
+{
+	let mut ph = Pharos::<usize>::default();
+	let gate   = Arc::new( AtomicBool::new( false ) );
+
+	let _all    = ph.observe( ObserveConfig::default()                      ).await.expect( "observe" );
+	let _filter = ph.observe( ObserveConfig::default().filter( |n| *n > 1 ) ).await.expect( "observe" );
+	let _gated  = ph.observe( ObserveConfig::default().gate( gate )         ).await.expect( "observe" );
+	let gone    = ph.observe( ObserveConfig::default()                      ).await.expect( "observe" );
+
+	drop( gone );
+
+	assert_eq!( 1, ph.notify( 1 ).await.expect( "notify" ) );
+	assert_eq!( 2, ph.notify( 2 ).await.expect( "notify" ) );
+	assert_eq!( 3, ph.num_observers() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn single()
+{
+	let mut ph = Pharos::<usize>::default();
+
+	assert_eq!( 0, ph.notify( 1 ).await.expect( "notify" ) );
+
+	let _events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	assert_eq!( 1, ph.notify( 1 ).await.expect( "notify" ) );
+
+	ph.set_buffer_limit( Some( 1 ), BufferPolicy::DropNewest );
+
+	assert_eq!( 0, ph.notify( 2 ).await.expect( "notify" ) );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn relays()
+{
+	let mut ph = Pharos::<usize>::default();
+
+	let _variant = ph.observe_variant    ( ObserveConfig::default(), |n| ( *n > 1 ).then_some( *n ) ).await.expect( "observe" );
+	let _output  = ph.observe_with_output( ObserveConfig::default(), |n| ( *n > 2 ).then_some( *n ) ).await.expect( "observe" );
+	let _expand  = ph.observe_expand     ( ObserveConfig::default(), |n| vec![ *n; *n / 4 ]         ).await.expect( "observe" );
+
+	assert_eq!( 0, ph.notify( 1 ).await.expect( "notify" ) );
+	assert_eq!( 1, ph.notify( 2 ).await.expect( "notify" ) );
+	assert_eq!( 2, ph.notify( 3 ).await.expect( "notify" ) );
+	assert_eq!( 3, ph.notify( 4 ).await.expect( "notify" ) );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn group_and_if()
+{
+	let mut ph = Pharos::<usize>::default();
+
+	assert_eq!( 0, ph.notify_if( &1, || true ).await.expect( "notify" ) );
+
+	let _one   = ph.observe( ObserveConfig::default().group( 1 ) ).await.expect( "observe" );
+	let _two   = ph.observe( ObserveConfig::default().group( 2 ) ).await.expect( "observe" );
+	let _plain = ph.observe( ObserveConfig::default()            ).await.expect( "observe" );
+
+	assert_eq!( 1, ph.notify_group( 1.into(), &1 ).await.expect( "notify" ) );
+	assert_eq!( 0, ph.notify_if   ( &1, || false ).await.expect( "notify" ) );
+	assert_eq!( 3, ph.notify_if   ( &1, || true  ).await.expect( "notify" ) );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph      = SharedPharos::<usize>::default();
+	let _events = ph.observe_shared( ObserveConfig::default().group( 1 ) ).await.expect( "observe" );
+
+	assert_eq!( 1, ph.notify      ( 1                 ).await.expect( "notify" ) );
+	assert_eq!( 1, ph.notify_group( 1.into(), &1      ).await.expect( "notify" ) );
+	assert_eq!( 1, ph.notify_if   ( &1      , || true ).await.expect( "notify" ) );
+}
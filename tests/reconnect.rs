@@ -0,0 +1,127 @@
+// Tested:
+//
+// ✔ the old stream drains and ends, the new one keeps the filter of the observer.
+// ✔ reconnect after the channel was closed, the observer is not removed in the mean time.
+// ✔ reconnect an observer that is not reconnectable or that was already reconnected.
+// ✔ reconnect after the pharos is closed or dropped.
+// ✔ a dropped reconnectable observer still frees it's slot.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn reconnect()
+{
+	let mut ph   = Pharos::<usize>::default();
+	let opts     = ObserveConfig::default().reconnectable().filter( |e| e % 2 == 0 );
+	let mut old  = ph.observe( opts ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+
+	let mut new = old.reconnect().expect( "reconnect" );
+
+	ph.send( 3 ).await.expect( "send" );
+	ph.send( 4 ).await.expect( "send" );
+
+	assert_eq!( 1, ph.num_observers() );
+	drop( ph );
+
+	assert_eq!( Some( 2 ), old.next().await );
+	assert_eq!( None     , old.next().await );
+
+	assert_eq!( Some( 4 ), new.next().await );
+	assert_eq!( None     , new.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn reconnect_after_close()
+{
+	let mut ph  = Pharos::<usize>::default();
+	let opts    = ObserveConfig::from( Channel::Bounded( 2 ) ).reconnectable();
+	let mut old = ph.observe( opts ).await.expect( "observe" );
+
+	old.close();
+
+	// This one is lost.
+	//
+	ph.send( 1 ).await.expect( "send" );
+
+	assert_eq!( 0, ph.num_observers() );
+	assert_eq!( 1, ph.storage_len()   );
+
+	let mut new = old.reconnect().expect( "reconnect" );
+
+	assert_eq!( 1, ph.num_observers() );
+
+	ph.send( 2 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( None     , old.next().await );
+	assert_eq!( Some( 2 ), new.next().await );
+	assert_eq!( None     , new.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn not_reconnectable()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	assert_eq!( ErrorKind::NotReconnectable, events.reconnect().unwrap_err().kind() );
+
+
+	let mut old = ph.observe( ObserveConfig::default().reconnectable() ).await.expect( "observe" );
+	let _new    = old.reconnect().expect( "reconnect" );
+
+	assert_eq!( ErrorKind::NotReconnectable, old.reconnect().unwrap_err().kind() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn reconnect_closed()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default().reconnectable() ).await.expect( "observe" );
+
+	ph.close().await.expect( "close" );
+
+	assert_eq!( ErrorKind::Closed, events.reconnect().unwrap_err().kind() );
+
+
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default().reconnectable() ).await.expect( "observe" );
+
+	drop( ph );
+
+	assert_eq!( ErrorKind::Closed, events.reconnect().unwrap_err().kind() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn drop_reconnectable()
+{
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe( ObserveConfig::default().reconnectable() ).await.expect( "observe" );
+
+	drop( events );
+
+	assert_eq!( 0, ph.num_observers() );
+
+	let _events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	assert_eq!( 1, ph.storage_len() );
+}
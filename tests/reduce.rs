@@ -0,0 +1,118 @@
+// Tested:
+//
+// ✔ events sent while one is pending are combined into one, consumed events start a new combination.
+// ✔ the sender never waits for the observer.
+// ✔ observing without a reducer returns ErrorKind::MissingReducer.
+// ✔ the pending event is delivered after the pharos is closed, or after closing the stream.
+// ✔ a reconnected observer keeps reducing.
+//
+mod common;
+
+use common::import::*;
+
+
+fn sum() -> ObserveConfig<usize>
+{
+	ObserveConfig::default().channel( Channel::Reduce ).reduce( |pending, new| pending + new )
+}
+
+
+
+#[ async_std::test ]
+//
+async fn reduce()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( sum() ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+
+	assert_eq!( Some( 6 ), events.next().await );
+
+	ph.send( 4 ).await.expect( "send" );
+
+	assert_eq!( Some( 4 ), events.next().await );
+
+	ph.send( 5 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( Some( 5 ), events.next().await );
+	assert_eq!( None     , events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn no_backpressure()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( sum() ).await.expect( "observe" );
+
+	for i in 0..100
+	{
+		assert!( futures::poll!( ph.send( i ) ).is_ready() );
+	}
+
+	assert_eq!( Some( 4950 ), events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn missing_reducer()
+{
+	let mut ph = Pharos::<usize>::default();
+	let result = ph.observe( Channel::Reduce.into() ).await;
+
+	assert_eq!( ErrorKind::MissingReducer, result.unwrap_err().kind() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn close()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( sum() ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	ph.close().await.expect( "close" );
+
+	assert_eq!( Some( 3 ), events.next().await );
+	assert_eq!( None     , events.next().await );
+
+
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( sum() ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	events.close();
+	ph.send( 2 ).await.expect( "send" );
+
+	assert_eq!( Some( 1 ), events.next().await );
+	assert_eq!( None     , events.next().await );
+	assert_eq!( 0        , ph.num_observers()  );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn reconnect()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( sum().reconnectable() ).await.expect( "observe" );
+	let mut new    = events.reconnect().expect( "reconnect" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+
+	assert_eq!( None     , events.next().await );
+	assert_eq!( Some( 3 ), new   .next().await );
+}
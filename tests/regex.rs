@@ -0,0 +1,53 @@
+// Tested:
+//
+// ✔ only events for which the extracted text matches the pattern are delivered.
+// ✔ an invalid pattern returns ErrorKind::InvalidPattern.
+//
+#![ cfg( feature = "regex" ) ]
+
+mod common;
+
+use common::import::*;
+
+
+#[ derive( Clone, Debug, PartialEq ) ]
+//
+struct Log
+{
+	msg: String,
+}
+
+
+fn log( msg: &str ) -> Log
+{
+	Log{ msg: msg.to_string() }
+}
+
+
+
+#[ async_std::test ]
+//
+async fn regex()
+{
+	let mut ph = Pharos::<Log>::default();
+	let filter = Filter::regex( |l: &Log| &l.msg, r"^(error|warn):" ).expect( "valid pattern" );
+	let events = ph.observe( filter.into() ).await.expect( "observe" );
+
+	ph.send( log( "error: disk full"   ) ).await.expect( "send" );
+	ph.send( log( "info: all is well"  ) ).await.expect( "send" );
+	ph.send( log( "warn: disk is slow" ) ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ log( "error: disk full" ), log( "warn: disk is slow" ) ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ test ]
+//
+fn invalid()
+{
+	let err = Filter::regex( |l: &Log| &l.msg, "(" ).unwrap_err();
+
+	assert_eq!( ErrorKind::InvalidPattern, err.kind() );
+}
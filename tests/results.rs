@@ -0,0 +1,105 @@
+// Tested:
+//
+// ✔ oks and errs are routed to their own stream, the filter applies to the result.
+// ✔ a full stream of errors doesn't hold back oks sent before the next error.
+// ✔ when one stream is dropped the other keeps receiving, the observer is removed when both are gone.
+// ✔ SharedPharos::observe_results.
+//
+mod common;
+
+use common::import::*;
+
+
+type Res = Result<usize, String>;
+
+
+
+#[ async_std::test ]
+//
+async fn route()
+{
+	let mut ph      = Pharos::<Res>::default();
+	let opts        = ObserveConfig::default().filter( |r| r != &Ok( 0 ) );
+	let (oks, errs) = ph.observe_results( opts ).await.expect( "observe" );
+
+	ph.send( Ok ( 1          ) ).await.expect( "send" );
+	ph.send( Err( "a".into() ) ).await.expect( "send" );
+	ph.send( Ok ( 0          ) ).await.expect( "send" );
+	ph.send( Ok ( 2          ) ).await.expect( "send" );
+	ph.send( Err( "b".into() ) ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2 ]                        , oks .collect::<Vec<_>>().await );
+	assert_eq!( vec![ "a".to_string(), "b".into() ] , errs.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn backpressure()
+{
+	let mut ph              = Pharos::<Res>::default();
+	let (mut oks, mut errs) = ph.observe_results( Channel::Bounded( 1 ).into() ).await.expect( "observe" );
+
+	ph.send( Err( "a".into() ) ).await.expect( "send" );
+	ph.send( Ok ( 1          ) ).await.expect( "send" );
+
+	assert_eq!( Some( 1 ), oks.next().await );
+
+	ph.send( Ok ( 2          ) ).await.expect( "send" );
+
+	{
+		let mut send = ph.send( Err( "b".into() ) );
+
+		assert!( futures::poll!( &mut send ).is_pending() );
+		assert_eq!( Some( "a".to_string() ), errs.next().await );
+		assert!( futures::poll!( &mut send ).is_ready() );
+	}
+
+	drop( ph );
+
+	assert_eq!( Some( 2               ), oks .next().await );
+	assert_eq!( Some( "b".to_string() ), errs.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn drop_one()
+{
+	let mut ph      = Pharos::<Res>::default();
+	let (oks, errs) = ph.observe_results( Channel::Bounded( 1 ).into() ).await.expect( "observe" );
+
+	drop( oks );
+
+	ph.send( Ok ( 1          ) ).await.expect( "send" );
+	ph.send( Ok ( 2          ) ).await.expect( "send" );
+	ph.send( Err( "a".into() ) ).await.expect( "send" );
+
+	assert_eq!( 1, ph.num_observers() );
+
+	drop( errs );
+
+	ph.send( Ok( 3 ) ).await.expect( "send" );
+
+	assert_eq!( 0, ph.num_observers() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph          = SharedPharos::<Res>::default();
+	let (oks, errs) = ph.observe_results( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.notify( Ok ( 1          ) ).await.expect( "notify" );
+	ph.notify( Err( "a".into() ) ).await.expect( "notify" );
+	drop( ph );
+
+	assert_eq!( vec![ 1               ], oks .collect::<Vec<_>>().await );
+	assert_eq!( vec![ "a".to_string() ], errs.collect::<Vec<_>>().await );
+}
@@ -0,0 +1,93 @@
+// Tested:
+//
+// ✔ events are tagged with the label of their stream and delivered in order per stream.
+// ✔ streams are polled round robin, a busy stream doesn't starve the others.
+// ✔ the result ends when all streams have ended.
+// ✔ dropping the future of next doesn't lose an event.
+// ✔ close closes all streams.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn labeled()
+{
+	let mut a = Pharos::<usize>::default();
+	let mut b = Pharos::<usize>::default();
+
+	let events_a = a.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let events_b = b.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	for i in 0..3 { a.send( i ).await.expect( "send" ); }
+	b.send( 10 ).await.expect( "send" );
+
+	drop( a );
+	drop( b );
+
+	let merged = Events::select_labeled( vec![ ( 'a', events_a ), ( 'b', events_b ) ] );
+
+	assert_eq!( vec![ ('a', 0), ('b', 10), ('a', 1), ('a', 2) ], merged.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn fair()
+{
+	let streams = vec!
+	[
+		( 0, (0..10).collect::<Events<usize>>() ),
+		( 1, (0.. 2).collect::<Events<usize>>() ),
+		( 2, (0.. 2).collect::<Events<usize>>() ),
+	];
+
+	let labels: Vec<_> = Events::select_labeled( streams ).take( 6 ).map( |(label, _)| label ).collect().await;
+
+	assert_eq!( vec![ 0, 1, 2, 0, 1, 2 ], labels );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn cancel_safe()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let events     = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let mut merged = Events::select_labeled( vec![ ( "ph", events ) ] );
+
+	assert!( futures::poll!( merged.next() ).is_pending() );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	assert_eq!( Some(( "ph", 1 )), merged.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn close()
+{
+	let mut a = Pharos::<usize>::default();
+	let mut b = Pharos::<usize>::default();
+
+	let events_a   = a.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let events_b   = b.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let mut merged = Events::select_labeled( vec![ ( 'a', events_a ), ( 'b', events_b ) ] );
+
+	a.send( 1 ).await.expect( "send" );
+	merged.close();
+
+	a.send( 2 ).await.expect( "send" );
+	b.send( 3 ).await.expect( "send" );
+
+	assert_eq!( Some(( 'a', 1 )), merged.next().await );
+	assert_eq!( None            , merged.next().await );
+	assert_eq!( 0               , a.num_observers()   );
+	assert_eq!( 0               , b.num_observers()   );
+}
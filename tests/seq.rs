@@ -0,0 +1,98 @@
+// Tested:
+//
+// ✔ events are numbered per observer, counting only events that pass the filter.
+// ✔ events skipped by the gate show up as gaps.
+// ✔ events discarded by BufferPolicy::DropOldest keep their number, so the gap is the real loss.
+// ✔ SharedPharos::observe_sequenced.
+//
+mod common;
+
+use common::import::*;
+use std::sync::atomic::{ AtomicBool, Ordering };
+
+
+#[ async_std::test ]
+//
+async fn sequenced()
+{
+	let mut ph = Pharos::<usize>::default();
+	let all    = ph.observe_sequenced( ObserveConfig::default()                            ).await.expect( "observe" );
+	let even   = ph.observe_sequenced( ObserveConfig::default().filter( |e| e % 2 == 0 ) ).await.expect( "observe" );
+
+	for i in 0..4
+	{
+		ph.send( i ).await.expect( "send" );
+	}
+
+	drop( ph );
+
+	assert_eq!
+	(
+		vec![ Seq{ seq: 0, event: 0 }, Seq{ seq: 1, event: 1 }, Seq{ seq: 2, event: 2 }, Seq{ seq: 3, event: 3 } ],
+		all.collect::<Vec<_>>().await
+	);
+
+	assert_eq!( vec![ Seq{ seq: 0, event: 0 }, Seq{ seq: 1, event: 2 } ], even.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn gap()
+{
+	let mut ph = Pharos::<usize>::default();
+	let gate   = Arc::new( AtomicBool::new( true ) );
+	let events = ph.observe_sequenced( ObserveConfig::default().gate( gate.clone() ) ).await.expect( "observe" );
+
+	ph.send( 0 ).await.expect( "send" );
+
+		gate.store( false, Ordering::Relaxed );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+
+		gate.store( true, Ordering::Relaxed );
+
+	ph.send( 3 ).await.expect( "send" );
+
+	drop( ph );
+
+	assert_eq!( vec![ Seq{ seq: 0, event: 0 }, Seq{ seq: 3, event: 3 } ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn drop_oldest()
+{
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe_sequenced( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.set_buffer_limit( Some( 2 ), BufferPolicy::DropOldest );
+
+	for i in 0..4
+	{
+		ph.send( i ).await.expect( "send" );
+	}
+
+	drop( ph );
+
+	assert_eq!( vec![ Seq{ seq: 2, event: 2 }, Seq{ seq: 3, event: 3 } ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph     = SharedPharos::<usize>::default();
+	let events = ph.observe_sequenced( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.notify( 5 ).await.expect( "notify" );
+	drop( ph );
+
+	assert_eq!( vec![ Seq{ seq: 0, event: 5 } ], events.collect::<Vec<_>>().await );
+}
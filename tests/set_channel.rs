@@ -0,0 +1,111 @@
+// Tested:
+//
+// ✔ switching to Channel::Reduce keeps the buffered events and only the latest new one.
+// ✔ after switching from a full bounded channel to an unbounded one, notifying no longer waits.
+// ✔ errors for observers that are not reconnectable and invalid channels.
+// ✔ no events are lost when switching while another thread notifies.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn reduce()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let opts       = ObserveConfig::default().channel( Channel::Bounded( 4 ) ).reconnectable();
+	let mut events = ph.observe( opts ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+
+	events.set_channel( Channel::Reduce ).expect( "set_channel" );
+
+	for i in 3..6 { ph.send( i ).await.expect( "send" ); }
+
+	assert_eq!( Some( 1 ), events.next().await );
+	assert_eq!( Some( 2 ), events.next().await );
+	assert_eq!( Some( 5 ), events.next().await );
+	assert_eq!( 1        , ph.num_observers()  );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn unbounded()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let opts       = ObserveConfig::default().channel( Channel::Bounded( 1 ) ).reconnectable();
+	let mut events = ph.observe( opts ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	// Dropping the pending notification drops 2.
+	//
+	assert!( futures::poll!( ph.send( 2 ) ).is_pending() );
+
+	events.set_channel( Channel::Unbounded ).expect( "set_channel" );
+
+	ph.send( 3 ).await.expect( "send" );
+	ph.send( 4 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 3, 4 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn errors()
+{
+	let mut ph        = Pharos::<usize>::default();
+	let mut plain     = ph.observe( ObserveConfig::default()                 ).await.expect( "observe" );
+	let mut reconnect = ph.observe( ObserveConfig::default().reconnectable() ).await.expect( "observe" );
+
+	assert_eq!( ErrorKind::NotReconnectable , plain    .set_channel( Channel::Unbounded    ).unwrap_err().kind() );
+	assert_eq!( ErrorKind::MinChannelSizeOne, reconnect.set_channel( Channel::Bounded ( 0 ) ).unwrap_err().kind() );
+	assert_eq!( ErrorKind::MissingPriority  , reconnect.set_channel( Channel::Priority( 2 ) ).unwrap_err().kind() );
+
+	drop( ph );
+
+	assert_eq!( ErrorKind::Closed, reconnect.set_channel( Channel::Unbounded ).unwrap_err().kind() );
+}
+
+
+
+#[ test ]
+//
+fn concurrent()
+{
+	const EVENTS: usize = 100_000;
+
+	let mut ph     = Pharos::<usize>::default();
+	let opts       = ObserveConfig::default().channel( Channel::Unbounded ).reconnectable();
+	let mut events = futures::executor::block_on( ph.observe( opts ) ).expect( "observe" );
+
+	let notifier = std::thread::spawn( move || futures::executor::block_on( async move
+	{
+		for i in 0..EVENTS
+		{
+			ph.send( i ).await.expect( "send" );
+		}
+	}));
+
+	for i in 0..
+	{
+		let channel = if i % 2 == 0 { Channel::Bounded( EVENTS ) } else { Channel::Unbounded };
+
+		if events.set_channel( channel ).is_err() { break; }
+	}
+
+	notifier.join().expect( "join notifier" );
+
+	let received = futures::executor::block_on( events.collect::<Vec<_>>() );
+
+	assert_eq!( EVENTS, received.len() );
+	assert!( received.windows( 2 ).all( |w| w[0] < w[1] ) );
+}
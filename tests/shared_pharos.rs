@@ -1,6 +1,10 @@
 // Tested:
 //
 // ✔ Use a SharedPharos confronted by backpressure and using the lock.
+// ✔ observe_sync_events with an event that is shared by reference between threads.
+// ✔ observe, notify and count observers through a shared reference.
+// ✔ observe after close returns ErrorKind::Closed and existing observers end.
+// ✔ all observers see events from concurrent tasks in the same order.
 // - A more involved test. Probably keep a global datastructure which records the order of operations
 //   and then assert everything happens in the expected order. Eg. verifies that the back pressure is
 //   actually doing something.
@@ -30,3 +34,127 @@ async fn basic()
 	assert_eq!( None, events.next().await );
 }
 
+
+
+
+// Arc<SharedEvent> needs SharedEvent to be Sync, which observe_sync_events enforces.
+//
+#[ async_std::test ]
+//
+async fn observe_sync_events()
+{
+	let shared = SharedPharos::<Arc<SharedEvent>>::default();
+	let mut events = shared.observe_sync_events( Channel::Bounded( 1 ).into() ).await.expect( "observe" );
+
+	let evt = Arc::new( SharedEvent{ time: "sync".into() } );
+	let tx  = shared.clone();
+	let snd = evt.clone();
+
+	thread::spawn( move || block_on( tx.notify( snd ) ).expect( "notify" ) );
+
+	assert_eq!( evt, events.next().await.unwrap() );
+}
+
+
+
+// Everything works through &self, so we can put it in an Arc without a lock of our own.
+//
+#[ async_std::test ]
+//
+async fn shared_reference()
+{
+	let shared = Arc::new( SharedPharos::from( Pharos::<IsisEvent>::new( 2 ) ) );
+
+	let mut a = shared.observe_shared( ObserveConfig::default() ).await.expect( "observe" );
+	let     b = shared.observe_shared( ObserveConfig::default() ).await.expect( "observe" );
+
+		assert_eq!( 2, shared.num_observers().await );
+
+	drop( b );
+	shared.notify( IsisEvent::Sail ).await.expect( "notify" );
+
+		assert_eq!( 1, shared.num_observers().await );
+		assert_eq!( 2, shared.storage_len  ().await );
+
+	assert_eq!( IsisEvent::Sail, a.next().await.unwrap() );
+}
+
+
+
+// After close, observe gives a clear error rather than a subscription that never receives anything.
+//
+#[ async_std::test ]
+//
+async fn observe_after_close()
+{
+	let shared     = SharedPharos::<IsisEvent>::default();
+	let mut events = shared.observe_shared( ObserveConfig::default() ).await.expect( "observe" );
+
+	shared.notify( IsisEvent::Sail ).await.expect( "notify" );
+	shared.close().await.expect( "close" );
+
+	let err = shared.observe_shared( ObserveConfig::default() ).await.unwrap_err();
+
+		assert_eq!( ErrorKind::Closed, err.kind() );
+		assert!( err.to_string().contains( "closed" ) );
+
+		assert_eq!( ErrorKind::Closed, shared.notify( IsisEvent::Dock ).await.unwrap_err().kind() );
+
+	assert_eq!( IsisEvent::Sail, events.next().await.unwrap() );
+	assert_eq!( None           , events.next().await          );
+}
+
+
+
+// The lock serializes notifications, so even with back pressure all observers agree on the order.
+//
+#[ async_std::test ]
+//
+async fn total_order()
+{
+	let shared        = SharedPharos::<usize>::default();
+	let mut observers = Vec::new();
+
+	for _ in 0..3
+	{
+		let events = shared.observe_shared( Channel::Bounded( 2 ).into() ).await.expect( "observe" );
+
+		observers.push( async_std::task::spawn( events.collect::<Vec<_>>() ) );
+	}
+
+	let producers: Vec<_> = ( 0..4 ).map( |p|
+	{
+		let shared = shared.clone();
+
+		async_std::task::spawn( async move
+		{
+			for i in 0..50
+			{
+				shared.notify( p * 100 + i ).await.expect( "notify" );
+			}
+		})
+
+	}).collect();
+
+	for p in producers { p.await; }
+
+	drop( shared );
+
+	let first = observers.remove( 0 ).await;
+
+	assert_eq!( 200, first.len() );
+
+	for o in observers
+	{
+		assert_eq!( first, o.await );
+	}
+
+	// Each producer's events are in the order it sent them.
+	//
+	for p in 0..4
+	{
+		let mine: Vec<_> = first.iter().filter( |n| *n / 100 == p ).collect();
+
+		assert!( mine.windows( 2 ).all( |w| w[0] < w[1] ) );
+	}
+}
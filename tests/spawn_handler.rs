@@ -0,0 +1,54 @@
+// Tested:
+//
+// ✔ every event is handled on the spawned task, the handle resolves when the stream ends.
+// ✔ dropping the handle cancels the task and drops the stream.
+//
+mod common;
+
+use common::import::*;
+use async_executors::AsyncStd;
+use std::{ sync::Mutex as SyncMutex, time::Duration };
+
+
+#[ async_std::test ]
+//
+async fn handle()
+{
+	let seen   = Arc::new( SyncMutex::new( Vec::new() ) );
+	let record = seen.clone();
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let handle = events.spawn_handler( &AsyncStd, move |n| record.lock().unwrap().push( n ) ).expect( "spawn" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	drop( ph );
+
+	handle.await;
+
+	assert_eq!( vec![ 1, 2 ], *seen.lock().unwrap() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn cancel()
+{
+	let mut ph = Pharos::<usize>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let handle = events.spawn_handler( &AsyncStd, |_| {} ).expect( "spawn" );
+
+	drop( handle );
+
+	// The task drops the stream next time it runs.
+	//
+	for _ in 0..100
+	{
+		if ph.num_observers() == 0 { return; }
+
+		async_std::task::sleep( Duration::from_millis( 5 ) ).await;
+	}
+
+	panic!( "the stream was not dropped" );
+}
@@ -0,0 +1,51 @@
+// Tested:
+//
+// ✔ events older than max_age when they are read are skipped, fresh ones are delivered in order.
+// ✔ the age is measured when the consumer polls, not when the event is sent.
+//
+mod common;
+
+use common::import::*;
+use std::time::{ Duration, Instant };
+
+
+#[ async_std::test ]
+//
+async fn drop_stale()
+{
+	let mut ph = Pharos::<( usize, u64 )>::default();
+	let events = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let events = events.drop_stale( Duration::from_secs( 5 ), |evt| Duration::from_secs( evt.1 ) );
+
+	ph.send(( 1, 0 )).await.expect( "send" );
+	ph.send(( 2, 9 )).await.expect( "send" );
+	ph.send(( 3, 5 )).await.expect( "send" );
+	ph.send(( 4, 6 )).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( vec![ 1, 3 ], events.map( |evt| evt.0 ).collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn backlog()
+{
+	let max_age    = Duration::from_millis( 20 );
+	let mut ph     = Pharos::<Instant>::default();
+	let events     = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let mut events = events.drop_stale( max_age, |at| at.elapsed() );
+
+	ph.send( Instant::now() ).await.expect( "send" );
+	ph.send( Instant::now() ).await.expect( "send" );
+
+	// The consumer is blocked while the backlog gets stale.
+	//
+	async_std::task::sleep( max_age * 2 ).await;
+
+	let live = Instant::now();
+	ph.send( live ).await.expect( "send" );
+
+	assert_eq!( Some( live ), events.next().await );
+}
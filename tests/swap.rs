@@ -0,0 +1,95 @@
+// Tested:
+//
+// ✔ after swapping, events go to the new observers, the old ones end when the returned set is dropped.
+// ✔ the returned set can be swapped back in.
+// ✔ swapping into a closed pharos drops the new observers.
+// ✔ SharedPharos::swap_observers.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn swap()
+{
+	let mut ph  = Pharos::<usize>::default();
+	let mut set = ObserverSet::default();
+
+	let mut old = ph .observe( ObserveConfig::default() ).await.expect( "observe" );
+	let mut new = set.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	let set = ph.swap_observers( set );
+
+	ph.send( 2 ).await.expect( "send" );
+	drop( set );
+
+	assert_eq!( Some( 1 ), old.next().await );
+	assert_eq!( None     , old.next().await );
+	assert_eq!( Some( 2 ), new.next().await );
+	assert_eq!( 1        , ph.num_observers() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn swap_back()
+{
+	let mut ph  = Pharos::<usize>::default();
+	let mut old = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	let set = ph.swap_observers( ObserverSet::new( 0 ) );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	let mut set = ph.swap_observers( set );
+
+	ph.send( 2 ).await.expect( "send" );
+	drop( ph );
+
+	assert_eq!( 0        , set.num_observers() );
+	assert_eq!( Some( 2 ), old.next().await    );
+	assert_eq!( None     , old.next().await    );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn closed()
+{
+	let mut ph  = Pharos::<usize>::default();
+	let mut set = ObserverSet::default();
+	let mut new = set.observe( ObserveConfig::default() ).await.expect( "observe" );
+
+	ph.close().await.expect( "close" );
+
+	let _ = ph.swap_observers( set );
+
+	assert_eq!( None, new.next().await  );
+	assert_eq!( 0   , ph.num_observers() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph      = SharedPharos::<usize>::default();
+	let mut set = ObserverSet::default();
+	let new     = set.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let mut old = ph.observe_shared( ObserveConfig::default() ).await.expect( "observe" );
+
+	let _ = ph.swap_observers( set ).await;
+
+	ph.notify( 1 ).await.expect( "notify" );
+	drop( ph );
+
+	assert_eq!( None     , old.next().await );
+	assert_eq!( vec![ 1 ], new.collect::<Vec<_>>().await );
+}
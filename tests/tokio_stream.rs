@@ -0,0 +1,26 @@
+// Tested:
+//
+// ✔ TokioEvents can be consumed with tokio_stream::StreamExt.
+// ✔ close is still reachable through the wrapper.
+//
+#![ cfg( feature = "tokio-stream" ) ]
+
+mod common;
+
+use common::{ *, import::* };
+
+
+#[ async_std::test ]
+//
+async fn tokio_stream()
+{
+	let mut isis   = Goddess::new();
+	let mut events = isis.observe( Channel::Bounded( 5 ).into() ).await.expect( "observe" ).into_tokio_stream();
+
+	isis.sail().await;
+	events.close();
+	isis.dock().await;
+
+	assert_eq!( Some( IsisEvent::Sail ), tokio_stream::StreamExt::next( &mut events ).await );
+	assert_eq!( None                   , tokio_stream::StreamExt::next( &mut events ).await );
+}
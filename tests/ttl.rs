@@ -0,0 +1,93 @@
+// Tested:
+//
+// ✔ the stream ends once the ttl has elapsed, after delivering the events that were sent.
+// ✔ the observer is removed from the observable.
+// ✔ the ttl counts from subscribing, not from the first poll.
+// ✔ the ttl applies to observers with a transformation.
+// ✔ the observable removes an expired observer that is never polled, and it's events are still delivered.
+//
+mod common;
+
+use common::import::*;
+use std::time::{ Duration, Instant };
+
+
+#[ async_std::test ]
+//
+async fn ttl()
+{
+	let ttl        = Duration::from_millis( 20 );
+	let mut ph     = Pharos::<usize>::default();
+	let start      = Instant::now();
+	let mut events = ph.observe( ObserveConfig::default().ttl( ttl, async_std::task::sleep ) ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+
+	assert_eq!( Some( 1 ), events.next().await );
+	assert_eq!( Some( 2 ), events.next().await );
+	assert_eq!( None     , events.next().await );
+
+	assert!( start.elapsed() >= ttl );
+
+	ph.send( 3 ).await.expect( "send" );
+
+	assert_eq!( 0, ph.num_observers() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn from_subscribing()
+{
+	let ttl        = Duration::from_millis( 20 );
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default().ttl( ttl, async_std::task::sleep ) ).await.expect( "observe" );
+
+	async_std::task::sleep( ttl * 2 ).await;
+
+	// The timer has already elapsed when the stream is first polled.
+	//
+	assert!( futures::poll!( events.next() ).is_ready() );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn relay()
+{
+	let ttl    = Duration::from_millis( 10 );
+	let mut ph = Pharos::<usize>::default();
+	let opts   = ObserveConfig::default().ttl( ttl, async_std::task::sleep );
+	let events = ph.observe_expand( opts, |n: &usize| vec![ *n, *n ] ).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+
+	assert_eq!( vec![ 1, 1 ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn unpolled()
+{
+	let ttl           = Duration::from_millis( 10 );
+	let mut ph        = Pharos::<usize>::default();
+	let mut lifecycle = ph.lifecycle_events();
+	let events        = ph.observe( ObserveConfig::default().ttl( ttl, async_std::task::sleep ) ).await.expect( "observe" );
+	let id            = events.id();
+
+	ph.send( 1 ).await.expect( "send" );
+	async_std::task::sleep( ttl * 2 ).await;
+
+	assert_eq!( 1, ph.gc() );
+	assert_eq!( 0, ph.notify( 2 ).await.expect( "notify" ) );
+
+	assert_eq!( Some( LifecycleEvent::Subscribed  { id, name  : None                       } ), lifecycle.next().await );
+	assert_eq!( Some( LifecycleEvent::Unsubscribed{ id, reason: UnsubscribeReason::Expired } ), lifecycle.next().await );
+
+	assert_eq!( vec![ 1 ], events.collect::<Vec<_>>().await );
+}
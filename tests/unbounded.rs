@@ -9,6 +9,7 @@
 // - ✔ Basic filter usage, only one event type should be returned.
 // - ✔ A filter that always returns true should get all events.
 // - ✔ A filter that always returns false should not get any events.
+// - ✔ observe_unbounded with and without filter.
 //
 mod common;
 
@@ -286,3 +287,28 @@ async fn filter_move()
 	assert_eq!( None           , events.next().await          );
 }
 
+
+
+// The shorthand takes an optional filter.
+//
+#[ async_std::test ]
+//
+async fn observe_unbounded()
+{
+	let mut isis = Goddess::new();
+
+	let mut docks = isis.observe_unbounded( Filter::Pointer( |e| *e == IsisEvent::Dock ) ).await.expect( "observe" );
+	let mut all   = isis.observe_unbounded( None ).await.expect( "observe" );
+
+	isis.sail().await;
+	isis.dock().await;
+
+	drop( isis );
+
+	assert_eq!( IsisEvent::Dock, docks.next().await.unwrap() );
+	assert_eq!( None           , docks.next().await          );
+
+	assert_eq!( IsisEvent::Sail, all.next().await.unwrap() );
+	assert_eq!( IsisEvent::Dock, all.next().await.unwrap() );
+	assert_eq!( None           , all.next().await          );
+}
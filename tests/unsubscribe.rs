@@ -0,0 +1,73 @@
+// Tested:
+//
+// ✔ the stream of an unsubscribed observer ends after delivering it's buffered events.
+// ✔ other observers keep receiving events.
+// ✔ unsubscribing an unknown id returns false.
+// ✔ the observer is reported with UnsubscribeReason::Removed.
+// ✔ a reconnectable observer can no longer reconnect.
+// ✔ SharedPharos::unsubscribe.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ async_std::test ]
+//
+async fn unsubscribe()
+{
+	let mut ph    = Pharos::<usize>::default();
+	let mut cycle = ph.lifecycle_events();
+	let removed   = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let other     = ph.observe( ObserveConfig::default() ).await.expect( "observe" );
+	let id        = removed.id();
+
+	ph.send( 1 ).await.expect( "send" );
+
+	assert!(  ph.unsubscribe( id ) );
+	assert!( !ph.unsubscribe( id ) );
+	assert_eq!( 1, ph.num_observers() );
+
+	ph.send( 2 ).await.expect( "send" );
+
+	assert_eq!( vec![ 1 ], removed.collect::<Vec<_>>().await );
+
+	drop( ph );
+
+	assert_eq!( vec![ 1, 2 ], other.collect::<Vec<_>>().await );
+
+	cycle.next().await;
+	cycle.next().await;
+
+	assert_eq!( Some( LifecycleEvent::Unsubscribed{ id, reason: UnsubscribeReason::Removed } ), cycle.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn reconnectable()
+{
+	let mut ph     = Pharos::<usize>::default();
+	let mut events = ph.observe( ObserveConfig::default().reconnectable() ).await.expect( "observe" );
+
+	assert!( ph.unsubscribe( events.id() ) );
+	assert!( events.reconnect().is_err() );
+	assert_eq!( None, events.next().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph     = SharedPharos::<usize>::default();
+	let events = ph.observe_shared( ObserveConfig::default() ).await.expect( "observe" );
+
+	assert!( ph.unsubscribe( events.id() ).await );
+
+	ph.notify( 1 ).await.expect( "notify" );
+
+	assert_eq!( Vec::<usize>::new(), events.collect::<Vec<_>>().await );
+}
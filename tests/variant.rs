@@ -0,0 +1,93 @@
+// Tested:
+//
+// ✔ only projected events are delivered, as the narrower type.
+// ✔ the filter runs before the projection.
+// ✔ SharedPharos::observe_variant.
+//
+mod common;
+
+use common::import::*;
+
+
+#[ derive( Clone, Debug, PartialEq ) ]
+//
+enum Conn
+{
+	Open          ,
+	Data( usize ) ,
+	Closed        ,
+}
+
+
+#[ derive( Clone, Debug, PartialEq ) ]
+//
+enum State
+{
+	Open   ,
+	Closed ,
+}
+
+
+fn state( evt: &Conn ) -> Option<State>
+{
+	match evt
+	{
+		Conn::Open    => Some( State::Open   ),
+		Conn::Closed  => Some( State::Closed ),
+		Conn::Data(_) => None                 ,
+	}
+}
+
+
+
+#[ async_std::test ]
+//
+async fn variant()
+{
+	let mut ph = Pharos::<Conn>::default();
+	let events = ph.observe_variant( ObserveConfig::default(), state ).await.expect( "observe" );
+
+	ph.send( Conn::Open      ).await.expect( "send" );
+	ph.send( Conn::Data( 3 ) ).await.expect( "send" );
+	ph.send( Conn::Closed    ).await.expect( "send" );
+
+	drop( ph );
+
+	assert_eq!( vec![ State::Open, State::Closed ], events.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn filter()
+{
+	let mut ph = Pharos::<Conn>::default();
+	let opts   = ObserveConfig::default().filter( |e| *e != Conn::Open );
+	let states = ph.observe_variant( opts, state ).await.expect( "observe" );
+
+	ph.send( Conn::Open      ).await.expect( "send" );
+	ph.send( Conn::Data( 3 ) ).await.expect( "send" );
+	ph.send( Conn::Closed    ).await.expect( "send" );
+
+	drop( ph );
+
+	assert_eq!( vec![ State::Closed ], states.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph     = SharedPharos::<Conn>::default();
+	let events = ph.observe_variant( ObserveConfig::default(), state ).await.expect( "observe" );
+
+	ph.notify( Conn::Data( 1 ) ).await.expect( "notify" );
+	ph.notify( Conn::Closed    ).await.expect( "notify" );
+
+	drop( ph );
+
+	assert_eq!( vec![ State::Closed ], events.collect::<Vec<_>>().await );
+}
@@ -0,0 +1,72 @@
+// Tested:
+//
+// ✔ only events for which the filter returns a value are delivered, together with that value.
+// ✔ the filter is called once per event, and the filter of the config runs before it.
+// ✔ SharedPharos::observe_with_output.
+//
+mod common;
+
+use common::import::*;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+
+
+#[ async_std::test ]
+//
+async fn with_output()
+{
+	let mut ph  = Pharos::<String>::default();
+	let numbers = ph.observe_with_output( ObserveConfig::default(), |s| s.parse::<u64>().ok() ).await.expect( "observe" );
+
+	ph.send( "1"  .to_string() ).await.expect( "send" );
+	ph.send( "one".to_string() ).await.expect( "send" );
+	ph.send( "2"  .to_string() ).await.expect( "send" );
+
+	drop( ph );
+
+	assert_eq!( vec![ ( "1".to_string(), 1 ), ( "2".to_string(), 2 ) ], numbers.collect::<Vec<_>>().await );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn once()
+{
+	let calls   = Arc::new( AtomicUsize::new( 0 ) );
+	let counter = calls.clone();
+	let mut ph  = Pharos::<usize>::default();
+	let opts    = ObserveConfig::default().filter( |n| *n > 1 );
+
+	let events = ph.observe_with_output( opts, move |n|
+	{
+		counter.fetch_add( 1, Ordering::SeqCst );
+		Some( n * 10 )
+
+	}).await.expect( "observe" );
+
+	ph.send( 1 ).await.expect( "send" );
+	ph.send( 2 ).await.expect( "send" );
+	ph.send( 3 ).await.expect( "send" );
+
+	drop( ph );
+
+	assert_eq!( vec![ (2, 20), (3, 30) ], events.collect::<Vec<_>>().await );
+	assert_eq!( 2, calls.load( Ordering::SeqCst ) );
+}
+
+
+
+#[ async_std::test ]
+//
+async fn shared()
+{
+	let ph     = SharedPharos::<usize>::default();
+	let events = ph.observe_with_output( ObserveConfig::default(), |n| n.checked_sub( 1 ) ).await.expect( "observe" );
+
+	ph.notify( 0 ).await.expect( "notify" );
+	ph.notify( 5 ).await.expect( "notify" );
+
+	drop( ph );
+
+	assert_eq!( vec![ (5, 4) ], events.collect::<Vec<_>>().await );
+}